@@ -4,7 +4,7 @@ use futures::{Future, Stream};
 use tokio_core::reactor::Core;
 
 // Hyper Imports
-use hyper::header::{HeaderName, HeaderValue, IF_NONE_MATCH};
+use hyper::header::{HeaderName, HeaderValue, CONTENT_TYPE, IF_NONE_MATCH};
 use hyper::StatusCode;
 use hyper::{self, Body, HeaderMap};
 use hyper::{Client, Request};
@@ -23,10 +23,13 @@ use serde_json;
 // Internal Library Imports
 use crate::errors::*;
 use crate::gists;
+use crate::issues;
 use crate::misc;
 use crate::notifications;
 use crate::orgs;
 use crate::repos;
+use crate::search;
+use crate::teams;
 use crate::users;
 use crate::util::url_join;
 
@@ -60,6 +63,8 @@ new_type!(DeleteQueryBuilder);
 
 new_type!(PatchQueryBuilder);
 
+new_type!(UploadQueryBuilder);
+
 new_type!(CustomQuery);
 
 exec!(CustomQuery);
@@ -178,6 +183,33 @@ impl Github {
         qb
     }
 
+    /// Begin building up a POST request to GitHub with a body that isn't JSON,
+    /// such as `text/plain` markdown. The body is sent as is with the given
+    /// Content-Type.
+    pub fn post_raw<T>(&self, body: T, content_type: &str) -> PostQueryBuilder
+    where
+        T: Into<Body>,
+    {
+        let mut qb: PostQueryBuilder = self.into();
+        qb.request = raw_body(qb.request, body.into(), content_type);
+        qb
+    }
+
+    /// Begin building up a POST request to `uploads.github.com`, which is
+    /// where release assets are uploaded to. The body is sent as is with the
+    /// given Content-Type, e.g. `application/zip`.
+    pub fn upload<T>(&self, body: T, content_type: &str) -> UploadQueryBuilder
+    where
+        T: Into<Body>,
+    {
+        let mut qb: UploadQueryBuilder = self.into();
+        if let Ok(ref mut qbr) = qb.request {
+            *qbr.get_mut().uri_mut() = hyper::Uri::from_static("https://uploads.github.com");
+        }
+        qb.request = raw_body(qb.request, body.into(), content_type);
+        qb
+    }
+
     /// Begin building up a PATCH request with data to GitHub
     pub fn patch<T>(&self, body: T) -> PatchQueryBuilder
     where
@@ -270,6 +302,9 @@ impl<'g> GetQueryBuilder<'g> {
     /// Query the gists endpoint
     func_client!(gists, gists::get::Gists<'g>);
 
+    /// Query the issues endpoint
+    func_client!(issues, issues::get::Issues<'g>);
+
     /// Query the orgs endpoint
     func_client!(orgs, orgs::get::Orgs<'g>);
 
@@ -279,6 +314,12 @@ impl<'g> GetQueryBuilder<'g> {
     /// Query the notifications endpoint
     func_client!(notifications, notifications::get::Notifications<'g>);
 
+    /// Query the search endpoint
+    func_client!(search, search::get::Search<'g>);
+
+    /// Query the teams endpoint
+    func_client!(teams, teams::get::Teams<'g>);
+
     /// Add an etag to the headers of the request
     pub fn set_etag(mut self, tag: impl Into<HeaderValue>) -> Self {
         match self.request {
@@ -307,6 +348,9 @@ impl<'g> PutQueryBuilder<'g> {
     /// if you need access to a hidden endpoint.
     func_client!(custom_endpoint, CustomQuery, endpoint_str);
     func_client!(user, users::put::User<'g>);
+    func_client!(repos, repos::put::Repos<'g>);
+    func_client!(orgs, orgs::put::Orgs<'g>);
+    func_client!(teams, teams::put::Teams<'g>);
     func_client!(gists, gists::put::Gists<'g>);
     func_client!(notifications, notifications::put::Notifications<'g>);
 
@@ -338,6 +382,9 @@ impl<'g> DeleteQueryBuilder<'g> {
     /// if you need access to a hidden endpoint.
     func_client!(custom_endpoint, CustomQuery, endpoint_str);
     func_client!(user, users::delete::User<'g>);
+    func_client!(repos, repos::delete::Repos<'g>);
+    func_client!(orgs, orgs::delete::Orgs<'g>);
+    func_client!(teams, teams::delete::Teams<'g>);
     func_client!(gists, gists::delete::Gists<'g>);
     func_client!(notifications, notifications::delete::Notifications<'g>);
 
@@ -370,6 +417,8 @@ impl<'g> PostQueryBuilder<'g> {
     func_client!(custom_endpoint, CustomQuery, endpoint_str);
     func_client!(user, users::post::User<'g>);
     func_client!(repos, repos::post::Repos<'g>);
+    func_client!(orgs, orgs::post::Orgs<'g>);
+    func_client!(teams, teams::post::Teams<'g>);
     func_client!(gists, gists::post::Gists<'g>);
 
     /// Add an etag to the headers of the request
@@ -400,6 +449,9 @@ impl<'g> PatchQueryBuilder<'g> {
     /// if you need access to a hidden endpoint.
     func_client!(custom_endpoint, CustomQuery, endpoint_str);
     func_client!(user, users::patch::User<'g>);
+    func_client!(repos, repos::patch::Repos<'g>);
+    func_client!(orgs, orgs::patch::Orgs<'g>);
+    func_client!(teams, teams::patch::Teams<'g>);
     func_client!(gists, gists::patch::Gists<'g>);
     func_client!(notifications, notifications::patch::Notifications<'g>);
 
@@ -418,6 +470,37 @@ impl<'g> PatchQueryBuilder<'g> {
     }
 }
 
+impl<'g> UploadQueryBuilder<'g> {
+    /// Pass in an endpoint not covered by the API in the form of the following:
+    ///
+    /// ```no_test
+    /// # Don't have the beginning / in it
+    /// repos/mgattozzi/github-rs/releases/1/assets?name=foo.zip
+    /// ```
+    ///
+    /// It can be whatever endpoint or url string that's needed. This will allow
+    /// you to get functionality out of the library as items are still added or
+    /// if you need access to a hidden endpoint.
+    func_client!(custom_endpoint, CustomQuery, endpoint_str);
+    func_client!(repos, repos::upload::Repos<'g>);
+}
+
+/// Swap the body of a request for one that's sent as is and set its
+/// Content-Type to match
+fn raw_body(
+    request: Result<RefCell<Request<Body>>>,
+    body: Body,
+    content_type: &str,
+) -> Result<RefCell<Request<Body>>> {
+    let mut req = request?;
+    let content_type = HeaderValue::from_str(content_type)?;
+    *req.get_mut().body_mut() = body;
+    req.get_mut()
+        .headers_mut()
+        .insert(CONTENT_TYPE, content_type);
+    Ok(req)
+}
+
 // From derivations of Github to the given type using a certain
 // request method
 from!(
@@ -431,6 +514,8 @@ from!(
         => "PATCH"
     @DeleteQueryBuilder
         => "DELETE"
+    @UploadQueryBuilder
+        => "POST"
 );
 
 // Custom Url based from impls
@@ -445,6 +530,8 @@ from!(
        => CustomQuery
     @DeleteQueryBuilder
        => CustomQuery
+    @UploadQueryBuilder
+       => CustomQuery
 );
 
 impl<'a> CustomQuery<'a> {
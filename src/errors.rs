@@ -7,6 +7,7 @@ error_chain! {
         HeaderValue(::hyper::header::InvalidHeaderValue);
         Uri(::hyper::http::uri::InvalidUriParts);
         HyperTls(::native_tls::Error) #[cfg(feature = "rust-native-tls")];
+        Base64(::base64::DecodeError);
         Io(::std::io::Error);
         SerdeJson(::serde_json::Error);
     }
@@ -1,10 +1,14 @@
 //! Helper functions for end users for GitHub response Headers
+use hmac::{Hmac, Mac};
 use hyper::header::{HeaderValue, ETAG, LAST_MODIFIED, USER_AGENT};
 use hyper::HeaderMap;
+use sha1::Sha1;
+use sha2::Sha256;
 use std::str::FromStr;
 
 /// Checks to see if a received payload from GitHub contains
-/// the GitHub-Hookshot header in the `UserAgent`.
+/// the GitHub-Hookshot header in the `UserAgent`. The header can be set by
+/// anyone, use `verify_signature` to actually authenticate a delivery.
 pub fn has_github_hookshot(head: &HeaderMap) -> bool {
     head.get(USER_AGENT).map_or(false, |user_agent| {
         user_agent
@@ -47,3 +51,95 @@ pub fn rate_limit_reset(head: &HeaderMap) -> Option<u32> {
         .map(|limit| u32::from_str(limit.to_str().unwrap_or("")).ok())
         .unwrap_or(None)
 }
+
+/// Extract which rate limit bucket, e.g. `core` or `search`, the rate limit
+/// headers of a response are counting against
+pub fn rate_limit_resource(head: &HeaderMap) -> Option<&str> {
+    head.get("X-RateLimit-Resource")
+        .and_then(|resource| resource.to_str().ok())
+}
+
+/// Extract the name of the event that triggered a webhook delivery from the
+/// `X-GitHub-Event` header if it exists
+pub fn github_event(head: &HeaderMap) -> Option<&str> {
+    head.get("X-GitHub-Event")
+        .and_then(|event| event.to_str().ok())
+}
+
+/// Extract the GUID identifying a webhook delivery from the
+/// `X-GitHub-Delivery` header if it exists
+pub fn github_delivery(head: &HeaderMap) -> Option<&str> {
+    head.get("X-GitHub-Delivery")
+        .and_then(|delivery| delivery.to_str().ok())
+}
+
+/// Extract the id of the webhook that sent a delivery from the
+/// `X-GitHub-Hook-ID` header if it exists
+pub fn github_hook_id(head: &HeaderMap) -> Option<u64> {
+    head.get("X-GitHub-Hook-ID")
+        .map(|id| u64::from_str(id.to_str().unwrap_or("")).ok())
+        .unwrap_or(None)
+}
+
+/// Verify the `X-Hub-Signature-256` header of a webhook delivery. This is
+/// the HMAC-SHA256 hex digest of the raw request body keyed with the secret
+/// set on the webhook. The comparison is done in constant time. Returns
+/// `false` if the header is missing or malformed.
+pub fn verify_signature_256(head: &HeaderMap, secret: &[u8], body: &[u8]) -> bool {
+    signature(head, "X-Hub-Signature-256", "sha256=")
+        .and_then(|sig| {
+            Hmac::<Sha256>::new_from_slice(secret).ok().map(|mut mac| {
+                mac.update(body);
+                mac.verify_slice(&sig).is_ok()
+            })
+        })
+        .unwrap_or(false)
+}
+
+/// Verify the legacy `X-Hub-Signature` header of a webhook delivery. This is
+/// the HMAC-SHA1 hex digest of the raw request body keyed with the secret set
+/// on the webhook. The comparison is done in constant time. Returns `false`
+/// if the header is missing or malformed. Prefer `verify_signature_256`
+/// whenever GitHub sends it.
+pub fn verify_signature_sha1(head: &HeaderMap, secret: &[u8], body: &[u8]) -> bool {
+    signature(head, "X-Hub-Signature", "sha1=")
+        .and_then(|sig| {
+            Hmac::<Sha1>::new_from_slice(secret).ok().map(|mut mac| {
+                mac.update(body);
+                mac.verify_slice(&sig).is_ok()
+            })
+        })
+        .unwrap_or(false)
+}
+
+/// Verify that a webhook delivery was signed with the given secret. The
+/// `X-Hub-Signature-256` header is checked if present, otherwise this falls
+/// back to the legacy `X-Hub-Signature` header. Unlike
+/// `has_github_hookshot` this can't be spoofed without knowing the secret.
+pub fn verify_signature(head: &HeaderMap, secret: &[u8], body: &[u8]) -> bool {
+    if head.contains_key("X-Hub-Signature-256") {
+        verify_signature_256(head, secret, body)
+    } else {
+        verify_signature_sha1(head, secret, body)
+    }
+}
+
+/// Pull the hex digest out of a signature header and decode it into bytes
+fn signature(head: &HeaderMap, name: &str, prefix: &str) -> Option<Vec<u8>> {
+    let hex = head
+        .get(name)?
+        .to_str()
+        .ok()?
+        .strip_prefix(prefix)?
+        .as_bytes();
+    if hex.len() % 2 != 0 {
+        return None;
+    }
+    hex.chunks(2)
+        .map(|pair| {
+            let high = (pair[0] as char).to_digit(16)?;
+            let low = (pair[1] as char).to_digit(16)?;
+            Some((high * 16 + low) as u8)
+        })
+        .collect()
+}
@@ -1 +1,24 @@
+//! Access the Issues portion of the GitHub API
+imports!();
+use crate::client::GetQueryBuilder;
 
+new_type!(Issues);
+
+from!(
+    @GetQueryBuilder
+        -> Issues = "issues"
+);
+
+params!(
+    @Issues
+        |?> filter = "filter"
+        |?> state = "state"
+        |?> labels = "labels"
+        |?> sort = "sort"
+        |?> direction = "direction"
+        |?> since = "since"
+        |?> per_page = "per_page"
+        |?> page = "page"
+);
+
+exec!(Issues);
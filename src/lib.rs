@@ -7,6 +7,8 @@
 
 #[macro_use]
 extern crate error_chain;
+#[macro_use]
+extern crate serde_derive;
 
 #[macro_use]
 mod macros;
@@ -18,11 +20,14 @@ pub mod gists;
 pub mod headers;
 pub mod issues;
 pub mod misc;
+pub mod models;
 pub mod notifications;
 pub mod orgs;
 pub mod repos;
 pub mod search;
 pub mod teams;
 pub mod users;
+pub mod webhooks;
 
+pub use crate::util::encode_path_segment;
 pub use hyper::{HeaderMap, StatusCode};
@@ -223,6 +223,7 @@ macro_rules! exec {
 macro_rules! impl_macro {
     ($(@$i: ident $(|=> $id1: ident -> $t1: ident)*|
      $(|=> $id2: ident -> $t2: ident = $e2: ident)*
+     $(|/> $id4: ident -> $t4: ident = $e4: ident)*
      $(|?> $id3: ident -> $t3: ident = $e3: ident)*)+
     )=> (
         $(
@@ -253,6 +254,26 @@ macro_rules! impl_macro {
                     }
                     self.into()
                 }
+            )*$(
+                // Like the arm above but for values that have to stay a
+                // single path segment, so `/` and `?` get escaped too
+                pub fn $id4(mut self, $e4: &str) -> $t4<'g> {
+                    if self.request.is_ok() {
+                        let mut req = self.request.unwrap();
+                        let segment = $crate::util::encode_path_segment($e4);
+                        let url = url_join(req.borrow().uri(), &segment);
+                        match url {
+                            Ok(u) => {
+                                *req.get_mut().uri_mut() = u;
+                                self.request = Ok(req);
+                            },
+                            Err(e) => {
+                                self.request = Err(e.into());
+                            }
+                        }
+                    }
+                    self.into()
+                }
             )*$(
                 pub fn $id3(mut self, $e3: &str) -> $t3<'g> {
                     self.parameter = Some($e3.to_string());
@@ -264,6 +285,41 @@ macro_rules! impl_macro {
     );
 }
 
+/// Using a small DSL like macro generate functions on a type that add a query
+/// parameter to the url and hand back the same type. Endpoints that take many
+/// optional parameters can then have them set in any order or left out.
+macro_rules! params {
+    ($(@$i: ident $(|?> $id: ident = $e: tt)*)+) => (
+        $(
+            impl<'g> $i <'g>{
+            $(
+                pub fn $id(mut self, value: &str) -> Self {
+                    // This is borrow checking abuse and about the only
+                    // time I'd do is_ok(). Essentially this allows us
+                    // to either pass the error message along or update
+                    // the url
+                    if self.request.is_ok() {
+                        // We've checked that this works
+                        let mut req = self.request.unwrap();
+                        let url = $crate::util::url_query(req.borrow().uri(), $e, value);
+                        match url {
+                            Ok(u) => {
+                                *req.get_mut().uri_mut() = u;
+                                self.request = Ok(req);
+                            },
+                            Err(e) => {
+                                self.request = Err(e.into());
+                            }
+                        }
+                    }
+                    self
+                }
+            )*
+            }
+        )+
+    );
+}
+
 /// A variation of `impl_macro` for the client module that allows partitioning of
 /// types. Create a function with a given name and return type. Used for
 /// creating functions for simple conversions from one type to another, where
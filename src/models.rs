@@ -0,0 +1,15 @@
+//! Types shared by REST responses and webhook payloads
+
+/// A GitHub account, e.g. the creator of a deployment or the sender of a
+/// webhook event
+#[derive(Debug, Clone, Deserialize)]
+pub struct User {
+    pub login: String,
+    pub id: u64,
+    pub node_id: Option<String>,
+    pub html_url: Option<String>,
+    #[serde(rename = "type")]
+    pub user_type: Option<String>,
+    #[serde(default)]
+    pub site_admin: bool,
+}
@@ -1 +1,130 @@
+//! Access the Organizations portion of the Github API
+imports!();
+use crate::client::DeleteQueryBuilder;
 
+new_type!(
+    Orgs
+    OrgsOrg
+    OrgsOrgHooks
+    OrgsOrgHooksId
+    OrgsOrgRulesets
+    OrgsOrgRulesetsId
+    OrgsOrgTeams
+    OrgsOrgTeamsSlug
+    OrgsOrgTeamsSlugDiscussions
+    OrgsOrgTeamsSlugDiscussionsNumber
+    OrgsOrgTeamsSlugDiscussionsNumberComments
+    OrgsOrgTeamsSlugDiscussionsNumberCommentsNumber
+    OrgsOrgTeamsSlugDiscussionsNumberCommentsNumberReactions
+    OrgsOrgTeamsSlugDiscussionsNumberCommentsNumberReactionsId
+    OrgsOrgTeamsSlugDiscussionsNumberReactions
+    OrgsOrgTeamsSlugDiscussionsNumberReactionsId
+    OrgsOrgTeamsSlugMemberships
+    OrgsOrgTeamsSlugMembershipsUsername
+    OrgsOrgTeamsSlugRepos
+    OrgsOrgTeamsSlugReposOwner
+    OrgsOrgTeamsSlugReposOwnerRepo
+);
+
+from!(
+    @DeleteQueryBuilder
+        -> Orgs = "orgs"
+    @Orgs
+        => OrgsOrg
+    @OrgsOrg
+        -> OrgsOrgTeams = "teams"
+        -> OrgsOrgRulesets = "rulesets"
+        -> OrgsOrgHooks = "hooks"
+    @OrgsOrgTeams
+        => OrgsOrgTeamsSlug
+    @OrgsOrgTeamsSlug
+        -> OrgsOrgTeamsSlugMemberships = "memberships"
+        -> OrgsOrgTeamsSlugRepos = "repos"
+        -> OrgsOrgTeamsSlugDiscussions = "discussions"
+    @OrgsOrgTeamsSlugMemberships
+        => OrgsOrgTeamsSlugMembershipsUsername
+    @OrgsOrgTeamsSlugRepos
+        => OrgsOrgTeamsSlugReposOwner
+    @OrgsOrgTeamsSlugReposOwner
+        => OrgsOrgTeamsSlugReposOwnerRepo
+    @OrgsOrgTeamsSlugDiscussions
+        => OrgsOrgTeamsSlugDiscussionsNumber
+    @OrgsOrgTeamsSlugDiscussionsNumber
+        -> OrgsOrgTeamsSlugDiscussionsNumberComments = "comments"
+        -> OrgsOrgTeamsSlugDiscussionsNumberReactions = "reactions"
+    @OrgsOrgTeamsSlugDiscussionsNumberComments
+        => OrgsOrgTeamsSlugDiscussionsNumberCommentsNumber
+    @OrgsOrgTeamsSlugDiscussionsNumberReactions
+        => OrgsOrgTeamsSlugDiscussionsNumberReactionsId
+    @OrgsOrgTeamsSlugDiscussionsNumberCommentsNumber
+        -> OrgsOrgTeamsSlugDiscussionsNumberCommentsNumberReactions = "reactions"
+    @OrgsOrgTeamsSlugDiscussionsNumberCommentsNumberReactions
+        => OrgsOrgTeamsSlugDiscussionsNumberCommentsNumberReactionsId
+    @OrgsOrgRulesets
+        => OrgsOrgRulesetsId
+    @OrgsOrgHooks
+        => OrgsOrgHooksId
+);
+
+impl_macro!(
+    @Orgs
+        |
+        |=> org -> OrgsOrg = org_str
+    @OrgsOrg
+        |=> teams -> OrgsOrgTeams
+        |=> rulesets -> OrgsOrgRulesets
+        |=> hooks -> OrgsOrgHooks
+        |
+    @OrgsOrgTeams
+        |
+        |=> slug -> OrgsOrgTeamsSlug = team_slug
+    @OrgsOrgTeamsSlug
+        |=> memberships -> OrgsOrgTeamsSlugMemberships
+        |=> repos -> OrgsOrgTeamsSlugRepos
+        |=> discussions -> OrgsOrgTeamsSlugDiscussions
+        |
+    @OrgsOrgTeamsSlugMemberships
+        |
+        |=> username -> OrgsOrgTeamsSlugMembershipsUsername = username_str
+    @OrgsOrgTeamsSlugRepos
+        |
+        |=> owner -> OrgsOrgTeamsSlugReposOwner = owner_str
+    @OrgsOrgTeamsSlugReposOwner
+        |
+        |=> repo -> OrgsOrgTeamsSlugReposOwnerRepo = repo_str
+    @OrgsOrgTeamsSlugDiscussions
+        |
+        |=> number -> OrgsOrgTeamsSlugDiscussionsNumber = discussion_number
+    @OrgsOrgTeamsSlugDiscussionsNumber
+        |=> comments -> OrgsOrgTeamsSlugDiscussionsNumberComments
+        |=> reactions -> OrgsOrgTeamsSlugDiscussionsNumberReactions
+        |
+    @OrgsOrgTeamsSlugDiscussionsNumberComments
+        |
+        |=> number -> OrgsOrgTeamsSlugDiscussionsNumberCommentsNumber = comment_number
+    @OrgsOrgTeamsSlugDiscussionsNumberReactions
+        |
+        |=> id -> OrgsOrgTeamsSlugDiscussionsNumberReactionsId = reaction_id
+    @OrgsOrgTeamsSlugDiscussionsNumberCommentsNumber
+        |=> reactions -> OrgsOrgTeamsSlugDiscussionsNumberCommentsNumberReactions
+        |
+    @OrgsOrgTeamsSlugDiscussionsNumberCommentsNumberReactions
+        |
+        |=> id -> OrgsOrgTeamsSlugDiscussionsNumberCommentsNumberReactionsId = reaction_id
+    @OrgsOrgRulesets
+        |
+        |=> id -> OrgsOrgRulesetsId = ruleset_id
+    @OrgsOrgHooks
+        |
+        |=> id -> OrgsOrgHooksId = org_hook_id_str
+);
+
+exec!(OrgsOrgHooksId);
+exec!(OrgsOrgRulesetsId);
+exec!(OrgsOrgTeamsSlug);
+exec!(OrgsOrgTeamsSlugDiscussionsNumber);
+exec!(OrgsOrgTeamsSlugDiscussionsNumberCommentsNumber);
+exec!(OrgsOrgTeamsSlugDiscussionsNumberCommentsNumberReactionsId);
+exec!(OrgsOrgTeamsSlugDiscussionsNumberReactionsId);
+exec!(OrgsOrgTeamsSlugMembershipsUsername);
+exec!(OrgsOrgTeamsSlugReposOwnerRepo);
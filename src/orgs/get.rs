@@ -8,6 +8,9 @@ new_type!(
     OrgsOrgEvents
     OrgsOrgHooks
     OrgsOrgHooksId
+    OrgsOrgHooksIdConfig
+    OrgsOrgHooksIdDeliveries
+    OrgsOrgHooksIdDeliveriesId
     OrgsOrgInvitations
     OrgsOrgIssues
     OrgsOrgMembers
@@ -18,7 +21,23 @@ new_type!(
     OrgsOrgPublicmembers
     OrgsOrgPublicmembersUsername
     OrgsOrgRepos
+    OrgsOrgRulesets
+    OrgsOrgRulesetsId
     OrgsOrgTeams
+    OrgsOrgTeamsSlug
+    OrgsOrgTeamsSlugDiscussions
+    OrgsOrgTeamsSlugDiscussionsNumber
+    OrgsOrgTeamsSlugDiscussionsNumberComments
+    OrgsOrgTeamsSlugDiscussionsNumberCommentsNumber
+    OrgsOrgTeamsSlugDiscussionsNumberCommentsNumberReactions
+    OrgsOrgTeamsSlugDiscussionsNumberReactions
+    OrgsOrgTeamsSlugMembers
+    OrgsOrgTeamsSlugMemberships
+    OrgsOrgTeamsSlugMembershipsUsername
+    OrgsOrgTeamsSlugRepos
+    OrgsOrgTeamsSlugReposOwner
+    OrgsOrgTeamsSlugReposOwnerRepo
+    OrgsOrgTeamsSlugTeams
 );
 
 from!(
@@ -36,6 +55,7 @@ from!(
         -> OrgsOrgPublicmembers = "public_members"
         -> OrgsOrgRepos = "repos"
         -> OrgsOrgTeams = "teams"
+        -> OrgsOrgRulesets = "rulesets"
     @OrgsOrgHooks
         => OrgsOrgHooksId
     @OrgsOrgMembers
@@ -44,6 +64,36 @@ from!(
         => OrgsOrgMembershipsUsername
     @OrgsOrgPublicmembers
         => OrgsOrgPublicmembersUsername
+    @OrgsOrgTeams
+        => OrgsOrgTeamsSlug
+    @OrgsOrgTeamsSlug
+        -> OrgsOrgTeamsSlugMembers = "members"
+        -> OrgsOrgTeamsSlugMemberships = "memberships"
+        -> OrgsOrgTeamsSlugRepos = "repos"
+        -> OrgsOrgTeamsSlugTeams = "teams"
+        -> OrgsOrgTeamsSlugDiscussions = "discussions"
+    @OrgsOrgTeamsSlugMemberships
+        => OrgsOrgTeamsSlugMembershipsUsername
+    @OrgsOrgTeamsSlugRepos
+        => OrgsOrgTeamsSlugReposOwner
+    @OrgsOrgTeamsSlugReposOwner
+        => OrgsOrgTeamsSlugReposOwnerRepo
+    @OrgsOrgTeamsSlugDiscussions
+        => OrgsOrgTeamsSlugDiscussionsNumber
+    @OrgsOrgTeamsSlugDiscussionsNumber
+        -> OrgsOrgTeamsSlugDiscussionsNumberComments = "comments"
+        -> OrgsOrgTeamsSlugDiscussionsNumberReactions = "reactions"
+    @OrgsOrgTeamsSlugDiscussionsNumberComments
+        => OrgsOrgTeamsSlugDiscussionsNumberCommentsNumber
+    @OrgsOrgTeamsSlugDiscussionsNumberCommentsNumber
+        -> OrgsOrgTeamsSlugDiscussionsNumberCommentsNumberReactions = "reactions"
+    @OrgsOrgRulesets
+        => OrgsOrgRulesetsId
+    @OrgsOrgHooksId
+        -> OrgsOrgHooksIdConfig = "config"
+        -> OrgsOrgHooksIdDeliveries = "deliveries"
+    @OrgsOrgHooksIdDeliveries
+        => OrgsOrgHooksIdDeliveriesId
 );
 
 impl_macro!(
@@ -60,6 +110,7 @@ impl_macro!(
         |=> public_members -> OrgsOrgPublicmembers
         |=> repos -> OrgsOrgRepos
         |=> teams -> OrgsOrgTeams
+        |=> rulesets -> OrgsOrgRulesets
         |
     @OrgsOrgHooks
         |
@@ -73,6 +124,78 @@ impl_macro!(
     @OrgsOrgPublicmembers
         |
         |=> username -> OrgsOrgPublicmembersUsername = org_public_members_username
+    @OrgsOrgTeams
+        |
+        |=> slug -> OrgsOrgTeamsSlug = team_slug
+    @OrgsOrgTeamsSlug
+        |=> members -> OrgsOrgTeamsSlugMembers
+        |=> memberships -> OrgsOrgTeamsSlugMemberships
+        |=> repos -> OrgsOrgTeamsSlugRepos
+        |=> teams -> OrgsOrgTeamsSlugTeams
+        |=> discussions -> OrgsOrgTeamsSlugDiscussions
+        |
+    @OrgsOrgTeamsSlugMemberships
+        |
+        |=> username -> OrgsOrgTeamsSlugMembershipsUsername = username_str
+    @OrgsOrgTeamsSlugRepos
+        |
+        |=> owner -> OrgsOrgTeamsSlugReposOwner = owner_str
+    @OrgsOrgTeamsSlugReposOwner
+        |
+        |=> repo -> OrgsOrgTeamsSlugReposOwnerRepo = repo_str
+    @OrgsOrgTeamsSlugDiscussions
+        |
+        |=> number -> OrgsOrgTeamsSlugDiscussionsNumber = discussion_number
+    @OrgsOrgTeamsSlugDiscussionsNumber
+        |=> comments -> OrgsOrgTeamsSlugDiscussionsNumberComments
+        |=> reactions -> OrgsOrgTeamsSlugDiscussionsNumberReactions
+        |
+    @OrgsOrgTeamsSlugDiscussionsNumberComments
+        |
+        |=> number -> OrgsOrgTeamsSlugDiscussionsNumberCommentsNumber = comment_number
+    @OrgsOrgTeamsSlugDiscussionsNumberCommentsNumber
+        |=> reactions -> OrgsOrgTeamsSlugDiscussionsNumberCommentsNumberReactions
+        |
+    @OrgsOrgRulesets
+        |
+        |=> id -> OrgsOrgRulesetsId = ruleset_id
+    @OrgsOrgHooksId
+        |=> config -> OrgsOrgHooksIdConfig
+        |=> deliveries -> OrgsOrgHooksIdDeliveries
+        |
+    @OrgsOrgHooksIdDeliveries
+        |
+        |=> id -> OrgsOrgHooksIdDeliveriesId = delivery_id
+);
+
+params!(
+    @OrgsOrgTeamsSlugMembers
+        |?> role = "role"
+        |?> per_page = "per_page"
+        |?> page = "page"
+    @OrgsOrgTeamsSlugDiscussions
+        |?> direction = "direction"
+        |?> pinned = "pinned"
+        |?> per_page = "per_page"
+        |?> page = "page"
+    @OrgsOrgTeamsSlugDiscussionsNumberComments
+        |?> direction = "direction"
+        |?> per_page = "per_page"
+        |?> page = "page"
+    @OrgsOrgTeamsSlugDiscussionsNumberReactions
+        |?> content = "content"
+        |?> per_page = "per_page"
+        |?> page = "page"
+    @OrgsOrgTeamsSlugDiscussionsNumberCommentsNumberReactions
+        |?> content = "content"
+        |?> per_page = "per_page"
+        |?> page = "page"
+    @OrgsOrgRulesets
+        |?> per_page = "per_page"
+        |?> page = "page"
+    @OrgsOrgHooksIdDeliveries
+        |?> per_page = "per_page"
+        |?> cursor = "cursor"
 );
 
 exec!(Orgs);
@@ -80,6 +203,9 @@ exec!(OrgsOrg);
 exec!(OrgsOrgEvents);
 exec!(OrgsOrgHooks);
 exec!(OrgsOrgHooksId);
+exec!(OrgsOrgHooksIdConfig);
+exec!(OrgsOrgHooksIdDeliveries);
+exec!(OrgsOrgHooksIdDeliveriesId);
 exec!(OrgsOrgInvitations);
 exec!(OrgsOrgIssues);
 exec!(OrgsOrgMembers);
@@ -89,4 +215,18 @@ exec!(OrgsOrgOutsidecollaborators);
 exec!(OrgsOrgPublicmembers);
 exec!(OrgsOrgPublicmembersUsername);
 exec!(OrgsOrgRepos);
+exec!(OrgsOrgRulesets);
+exec!(OrgsOrgRulesetsId);
 exec!(OrgsOrgTeams);
+exec!(OrgsOrgTeamsSlug);
+exec!(OrgsOrgTeamsSlugDiscussions);
+exec!(OrgsOrgTeamsSlugDiscussionsNumber);
+exec!(OrgsOrgTeamsSlugDiscussionsNumberComments);
+exec!(OrgsOrgTeamsSlugDiscussionsNumberCommentsNumber);
+exec!(OrgsOrgTeamsSlugDiscussionsNumberCommentsNumberReactions);
+exec!(OrgsOrgTeamsSlugDiscussionsNumberReactions);
+exec!(OrgsOrgTeamsSlugMembers);
+exec!(OrgsOrgTeamsSlugMembershipsUsername);
+exec!(OrgsOrgTeamsSlugRepos);
+exec!(OrgsOrgTeamsSlugReposOwnerRepo);
+exec!(OrgsOrgTeamsSlugTeams);
@@ -1 +1,78 @@
+//! Access the Organizations portion of the Github API
+imports!();
+use crate::client::PatchQueryBuilder;
 
+new_type!(
+    Orgs
+    OrgsOrg
+    OrgsOrgHooks
+    OrgsOrgHooksId
+    OrgsOrgHooksIdConfig
+    OrgsOrgTeams
+    OrgsOrgTeamsSlug
+    OrgsOrgTeamsSlugDiscussions
+    OrgsOrgTeamsSlugDiscussionsNumber
+    OrgsOrgTeamsSlugDiscussionsNumberComments
+    OrgsOrgTeamsSlugDiscussionsNumberCommentsNumber
+);
+
+from!(
+    @PatchQueryBuilder
+        -> Orgs = "orgs"
+    @Orgs
+        => OrgsOrg
+    @OrgsOrg
+        -> OrgsOrgTeams = "teams"
+        -> OrgsOrgHooks = "hooks"
+    @OrgsOrgTeams
+        => OrgsOrgTeamsSlug
+    @OrgsOrgTeamsSlug
+        -> OrgsOrgTeamsSlugDiscussions = "discussions"
+    @OrgsOrgTeamsSlugDiscussions
+        => OrgsOrgTeamsSlugDiscussionsNumber
+    @OrgsOrgTeamsSlugDiscussionsNumber
+        -> OrgsOrgTeamsSlugDiscussionsNumberComments = "comments"
+    @OrgsOrgTeamsSlugDiscussionsNumberComments
+        => OrgsOrgTeamsSlugDiscussionsNumberCommentsNumber
+    @OrgsOrgHooks
+        => OrgsOrgHooksId
+    @OrgsOrgHooksId
+        -> OrgsOrgHooksIdConfig = "config"
+);
+
+impl_macro!(
+    @Orgs
+        |
+        |=> org -> OrgsOrg = org_str
+    @OrgsOrg
+        |=> teams -> OrgsOrgTeams
+        |=> hooks -> OrgsOrgHooks
+        |
+    @OrgsOrgTeams
+        |
+        |=> slug -> OrgsOrgTeamsSlug = team_slug
+    @OrgsOrgTeamsSlug
+        |=> discussions -> OrgsOrgTeamsSlugDiscussions
+        |
+    @OrgsOrgTeamsSlugDiscussions
+        |
+        |=> number -> OrgsOrgTeamsSlugDiscussionsNumber = discussion_number
+    @OrgsOrgTeamsSlugDiscussionsNumber
+        |=> comments -> OrgsOrgTeamsSlugDiscussionsNumberComments
+        |
+    @OrgsOrgTeamsSlugDiscussionsNumberComments
+        |
+        |=> number -> OrgsOrgTeamsSlugDiscussionsNumberCommentsNumber = comment_number
+    @OrgsOrgHooks
+        |
+        |=> id -> OrgsOrgHooksId = org_hook_id_str
+    @OrgsOrgHooksId
+        |=> config -> OrgsOrgHooksIdConfig
+        |
+);
+
+exec!(OrgsOrgHooksId);
+exec!(OrgsOrgHooksIdConfig);
+exec!(OrgsOrgTeamsSlug);
+exec!(OrgsOrgTeamsSlugDiscussionsNumber);
+exec!(OrgsOrgTeamsSlugDiscussionsNumberCommentsNumber);
@@ -1 +1,113 @@
+//! Access the Organizations portion of the Github API
+imports!();
+use crate::client::PostQueryBuilder;
 
+new_type!(
+    Orgs
+    OrgsOrg
+    OrgsOrgHooks
+    OrgsOrgHooksId
+    OrgsOrgHooksIdDeliveries
+    OrgsOrgHooksIdDeliveriesId
+    OrgsOrgHooksIdDeliveriesIdAttempts
+    OrgsOrgHooksIdPings
+    OrgsOrgRepos
+    OrgsOrgRulesets
+    OrgsOrgTeams
+    OrgsOrgTeamsSlug
+    OrgsOrgTeamsSlugDiscussions
+    OrgsOrgTeamsSlugDiscussionsNumber
+    OrgsOrgTeamsSlugDiscussionsNumberComments
+    OrgsOrgTeamsSlugDiscussionsNumberCommentsNumber
+    OrgsOrgTeamsSlugDiscussionsNumberCommentsNumberReactions
+    OrgsOrgTeamsSlugDiscussionsNumberReactions
+);
+
+from!(
+    @PostQueryBuilder
+        -> Orgs = "orgs"
+    @Orgs
+        => OrgsOrg
+    @OrgsOrg
+        -> OrgsOrgTeams = "teams"
+        -> OrgsOrgRepos = "repos"
+        -> OrgsOrgRulesets = "rulesets"
+        -> OrgsOrgHooks = "hooks"
+    @OrgsOrgTeams
+        => OrgsOrgTeamsSlug
+    @OrgsOrgTeamsSlug
+        -> OrgsOrgTeamsSlugDiscussions = "discussions"
+    @OrgsOrgTeamsSlugDiscussions
+        => OrgsOrgTeamsSlugDiscussionsNumber
+    @OrgsOrgTeamsSlugDiscussionsNumber
+        -> OrgsOrgTeamsSlugDiscussionsNumberComments = "comments"
+        -> OrgsOrgTeamsSlugDiscussionsNumberReactions = "reactions"
+    @OrgsOrgTeamsSlugDiscussionsNumberComments
+        => OrgsOrgTeamsSlugDiscussionsNumberCommentsNumber
+    @OrgsOrgTeamsSlugDiscussionsNumberCommentsNumber
+        -> OrgsOrgTeamsSlugDiscussionsNumberCommentsNumberReactions = "reactions"
+    @OrgsOrgHooks
+        => OrgsOrgHooksId
+    @OrgsOrgHooksId
+        -> OrgsOrgHooksIdPings = "pings"
+        -> OrgsOrgHooksIdDeliveries = "deliveries"
+    @OrgsOrgHooksIdDeliveries
+        => OrgsOrgHooksIdDeliveriesId
+    @OrgsOrgHooksIdDeliveriesId
+        -> OrgsOrgHooksIdDeliveriesIdAttempts = "attempts"
+);
+
+impl_macro!(
+    @Orgs
+        |
+        |=> org -> OrgsOrg = org_str
+    @OrgsOrg
+        |=> teams -> OrgsOrgTeams
+        |=> repos -> OrgsOrgRepos
+        |=> rulesets -> OrgsOrgRulesets
+        |=> hooks -> OrgsOrgHooks
+        |
+    @OrgsOrgTeams
+        |
+        |=> slug -> OrgsOrgTeamsSlug = team_slug
+    @OrgsOrgTeamsSlug
+        |=> discussions -> OrgsOrgTeamsSlugDiscussions
+        |
+    @OrgsOrgTeamsSlugDiscussions
+        |
+        |=> number -> OrgsOrgTeamsSlugDiscussionsNumber = discussion_number
+    @OrgsOrgTeamsSlugDiscussionsNumber
+        |=> comments -> OrgsOrgTeamsSlugDiscussionsNumberComments
+        |=> reactions -> OrgsOrgTeamsSlugDiscussionsNumberReactions
+        |
+    @OrgsOrgTeamsSlugDiscussionsNumberComments
+        |
+        |=> number -> OrgsOrgTeamsSlugDiscussionsNumberCommentsNumber = comment_number
+    @OrgsOrgTeamsSlugDiscussionsNumberCommentsNumber
+        |=> reactions -> OrgsOrgTeamsSlugDiscussionsNumberCommentsNumberReactions
+        |
+    @OrgsOrgHooks
+        |
+        |=> id -> OrgsOrgHooksId = org_hook_id_str
+    @OrgsOrgHooksId
+        |=> pings -> OrgsOrgHooksIdPings
+        |=> deliveries -> OrgsOrgHooksIdDeliveries
+        |
+    @OrgsOrgHooksIdDeliveries
+        |
+        |=> id -> OrgsOrgHooksIdDeliveriesId = delivery_id
+    @OrgsOrgHooksIdDeliveriesId
+        |=> attempts -> OrgsOrgHooksIdDeliveriesIdAttempts
+        |
+);
+
+exec!(OrgsOrgHooks);
+exec!(OrgsOrgHooksIdDeliveriesIdAttempts);
+exec!(OrgsOrgHooksIdPings);
+exec!(OrgsOrgRepos);
+exec!(OrgsOrgRulesets);
+exec!(OrgsOrgTeams);
+exec!(OrgsOrgTeamsSlugDiscussions);
+exec!(OrgsOrgTeamsSlugDiscussionsNumberComments);
+exec!(OrgsOrgTeamsSlugDiscussionsNumberCommentsNumberReactions);
+exec!(OrgsOrgTeamsSlugDiscussionsNumberReactions);
@@ -1 +1,73 @@
+//! Access the Organizations portion of the Github API
+imports!();
+use crate::client::PutQueryBuilder;
 
+new_type!(
+    Orgs
+    OrgsOrg
+    OrgsOrgRulesets
+    OrgsOrgRulesetsId
+    OrgsOrgTeams
+    OrgsOrgTeamsSlug
+    OrgsOrgTeamsSlugMemberships
+    OrgsOrgTeamsSlugMembershipsUsername
+    OrgsOrgTeamsSlugRepos
+    OrgsOrgTeamsSlugReposOwner
+    OrgsOrgTeamsSlugReposOwnerRepo
+);
+
+from!(
+    @PutQueryBuilder
+        -> Orgs = "orgs"
+    @Orgs
+        => OrgsOrg
+    @OrgsOrg
+        -> OrgsOrgTeams = "teams"
+        -> OrgsOrgRulesets = "rulesets"
+    @OrgsOrgTeams
+        => OrgsOrgTeamsSlug
+    @OrgsOrgTeamsSlug
+        -> OrgsOrgTeamsSlugMemberships = "memberships"
+        -> OrgsOrgTeamsSlugRepos = "repos"
+    @OrgsOrgTeamsSlugMemberships
+        => OrgsOrgTeamsSlugMembershipsUsername
+    @OrgsOrgTeamsSlugRepos
+        => OrgsOrgTeamsSlugReposOwner
+    @OrgsOrgTeamsSlugReposOwner
+        => OrgsOrgTeamsSlugReposOwnerRepo
+    @OrgsOrgRulesets
+        => OrgsOrgRulesetsId
+);
+
+impl_macro!(
+    @Orgs
+        |
+        |=> org -> OrgsOrg = org_str
+    @OrgsOrg
+        |=> teams -> OrgsOrgTeams
+        |=> rulesets -> OrgsOrgRulesets
+        |
+    @OrgsOrgTeams
+        |
+        |=> slug -> OrgsOrgTeamsSlug = team_slug
+    @OrgsOrgTeamsSlug
+        |=> memberships -> OrgsOrgTeamsSlugMemberships
+        |=> repos -> OrgsOrgTeamsSlugRepos
+        |
+    @OrgsOrgTeamsSlugMemberships
+        |
+        |=> username -> OrgsOrgTeamsSlugMembershipsUsername = username_str
+    @OrgsOrgTeamsSlugRepos
+        |
+        |=> owner -> OrgsOrgTeamsSlugReposOwner = owner_str
+    @OrgsOrgTeamsSlugReposOwner
+        |
+        |=> repo -> OrgsOrgTeamsSlugReposOwnerRepo = repo_str
+    @OrgsOrgRulesets
+        |
+        |=> id -> OrgsOrgRulesetsId = ruleset_id
+);
+
+exec!(OrgsOrgRulesetsId);
+exec!(OrgsOrgTeamsSlugMembershipsUsername);
+exec!(OrgsOrgTeamsSlugReposOwnerRepo);
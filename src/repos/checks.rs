@@ -0,0 +1,368 @@
+//! Typed bodies for creating and updating check runs
+//!
+//! GitHub only takes 50 annotations per request, so a run with more than that
+//! is created with the first batch and then updated with the rest:
+//!
+//! ```text
+//! let run = CreateCheckRun::new("lint", &sha)
+//!     .conclusion("failure")
+//!     .output(CheckRunOutput::new("Lint", "120 warnings").annotations(warnings));
+//! let (create, updates) = run.batched();
+//! let (_, _, created) = g.post(create)
+//!     .repos().owner("o").repo("r")
+//!     .check_runs()
+//!     .execute::<CheckRun>()?;
+//! let id = created.unwrap().id.to_string();
+//! for update in updates {
+//!     g.patch(update)
+//!         .repos().owner("o").repo("r")
+//!         .check_runs().id(&id)
+//!         .execute::<CheckRun>()?;
+//! }
+//! ```
+use serde_json::Value;
+
+/// The most annotations GitHub accepts in a single request
+pub const MAX_ANNOTATIONS: usize = 50;
+
+/// Body for `POST /repos/:owner/:repo/check-runs`
+#[derive(Debug, Clone, Default, Serialize, PartialEq)]
+pub struct CreateCheckRun {
+    pub name: String,
+    pub head_sha: String,
+    #[serde(flatten)]
+    pub fields: CheckRunFields,
+}
+
+/// Body for `PATCH /repos/:owner/:repo/check-runs/:id`
+#[derive(Debug, Clone, Default, Serialize, PartialEq)]
+pub struct UpdateCheckRun {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(flatten)]
+    pub fields: CheckRunFields,
+}
+
+/// The fields shared by creating and updating a check run
+#[derive(Debug, Clone, Default, Serialize, PartialEq)]
+pub struct CheckRunFields {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub details_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub external_id: Option<String>,
+    /// `queued`, `in_progress` or `completed`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub started_at: Option<String>,
+    /// `action_required`, `cancelled`, `failure`, `neutral`, `success`,
+    /// `skipped` or `timed_out`. Setting it completes the run.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub conclusion: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub completed_at: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub output: Option<CheckRunOutput>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub actions: Vec<CheckRunAction>,
+}
+
+macro_rules! check_run_setters {
+    ($t: ident) => {
+        impl $t {
+            pub fn details_url(mut self, url: &str) -> Self {
+                self.fields.details_url = Some(url.to_string());
+                self
+            }
+
+            pub fn external_id(mut self, id: &str) -> Self {
+                self.fields.external_id = Some(id.to_string());
+                self
+            }
+
+            pub fn status(mut self, status: &str) -> Self {
+                self.fields.status = Some(status.to_string());
+                self
+            }
+
+            pub fn started_at(mut self, timestamp: &str) -> Self {
+                self.fields.started_at = Some(timestamp.to_string());
+                self
+            }
+
+            pub fn conclusion(mut self, conclusion: &str) -> Self {
+                self.fields.conclusion = Some(conclusion.to_string());
+                self
+            }
+
+            pub fn completed_at(mut self, timestamp: &str) -> Self {
+                self.fields.completed_at = Some(timestamp.to_string());
+                self
+            }
+
+            pub fn output(mut self, output: CheckRunOutput) -> Self {
+                self.fields.output = Some(output);
+                self
+            }
+
+            pub fn action(mut self, action: CheckRunAction) -> Self {
+                self.fields.actions.push(action);
+                self
+            }
+        }
+    };
+}
+
+check_run_setters!(CreateCheckRun);
+check_run_setters!(UpdateCheckRun);
+
+impl CreateCheckRun {
+    pub fn new(name: &str, head_sha: &str) -> Self {
+        CreateCheckRun {
+            name: name.to_string(),
+            head_sha: head_sha.to_string(),
+            fields: CheckRunFields::default(),
+        }
+    }
+
+    /// Split the check run into a create body holding the first
+    /// `MAX_ANNOTATIONS` annotations and updates holding the rest. The status,
+    /// conclusion and completion time are moved to the last request so the
+    /// run isn't completed before all of its annotations are in.
+    pub fn batched(mut self) -> (CreateCheckRun, Vec<UpdateCheckRun>) {
+        let mut batches = self.fields.batched().into_iter();
+        // batched always hands back at least one set of fields
+        self.fields = batches.next().unwrap_or_default();
+        let updates = batches.map(UpdateCheckRun::from_fields).collect();
+        (self, updates)
+    }
+}
+
+impl UpdateCheckRun {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn name(mut self, name: &str) -> Self {
+        self.name = Some(name.to_string());
+        self
+    }
+
+    /// Split the update into requests of at most `MAX_ANNOTATIONS`
+    /// annotations each. The status, conclusion and completion time are sent
+    /// with the last one.
+    pub fn batched(self) -> Vec<UpdateCheckRun> {
+        let name = self.name;
+        let mut updates: Vec<_> = self
+            .fields
+            .batched()
+            .into_iter()
+            .map(UpdateCheckRun::from_fields)
+            .collect();
+        updates[0].name = name;
+        updates
+    }
+
+    fn from_fields(fields: CheckRunFields) -> Self {
+        UpdateCheckRun { name: None, fields }
+    }
+}
+
+impl CheckRunFields {
+    fn batched(mut self) -> Vec<CheckRunFields> {
+        let mut output = match self.output.take() {
+            Some(output) if output.annotations.len() > MAX_ANNOTATIONS => output,
+            output => {
+                self.output = output;
+                return vec![self];
+            }
+        };
+        let annotations = std::mem::take(&mut output.annotations);
+        let status = self.status.take();
+        let conclusion = self.conclusion.take();
+        let completed_at = self.completed_at.take();
+
+        let mut batches: Vec<CheckRunFields> = annotations
+            .chunks(MAX_ANNOTATIONS)
+            .enumerate()
+            .map(|(i, annotations)| {
+                let mut batch = CheckRunOutput {
+                    annotations: annotations.to_vec(),
+                    ..output.clone()
+                };
+                if i > 0 {
+                    batch.images.clear();
+                }
+                CheckRunFields {
+                    output: Some(batch),
+                    ..Default::default()
+                }
+            })
+            .collect();
+        batches[0] = CheckRunFields {
+            output: batches[0].output.take(),
+            ..self
+        };
+        let last = batches.len() - 1;
+        batches[last].status = status;
+        batches[last].conclusion = conclusion;
+        batches[last].completed_at = completed_at;
+        batches
+    }
+}
+
+/// What a check run reports. `title` and `summary` are required whenever
+/// `output` is sent.
+#[derive(Debug, Clone, Default, Serialize, PartialEq)]
+pub struct CheckRunOutput {
+    pub title: String,
+    pub summary: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub text: Option<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub annotations: Vec<Annotation>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub images: Vec<Image>,
+}
+
+impl CheckRunOutput {
+    pub fn new(title: &str, summary: &str) -> Self {
+        CheckRunOutput {
+            title: title.to_string(),
+            summary: summary.to_string(),
+            ..Default::default()
+        }
+    }
+
+    /// Markdown with the details of the run
+    pub fn text(mut self, text: &str) -> Self {
+        self.text = Some(text.to_string());
+        self
+    }
+
+    /// Add annotations. Any number can be added, use `batched` on the check
+    /// run to send them in requests GitHub will accept.
+    pub fn annotations<I>(mut self, annotations: I) -> Self
+    where
+        I: IntoIterator<Item = Annotation>,
+    {
+        self.annotations.extend(annotations);
+        self
+    }
+
+    pub fn image(mut self, image: Image) -> Self {
+        self.images.push(image);
+        self
+    }
+}
+
+/// A comment on a range of lines in a file
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct Annotation {
+    pub path: String,
+    pub start_line: u64,
+    pub end_line: u64,
+    /// Columns can only be given when the annotation is on a single line
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_column: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_column: Option<u64>,
+    /// `notice`, `warning` or `failure`
+    pub annotation_level: String,
+    pub message: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub raw_details: Option<String>,
+}
+
+impl Annotation {
+    pub fn new(path: &str, start_line: u64, end_line: u64, level: &str, message: &str) -> Self {
+        Annotation {
+            path: path.to_string(),
+            start_line,
+            end_line,
+            start_column: None,
+            end_column: None,
+            annotation_level: level.to_string(),
+            message: message.to_string(),
+            title: None,
+            raw_details: None,
+        }
+    }
+
+    pub fn columns(mut self, start: u64, end: u64) -> Self {
+        self.start_column = Some(start);
+        self.end_column = Some(end);
+        self
+    }
+
+    pub fn title(mut self, title: &str) -> Self {
+        self.title = Some(title.to_string());
+        self
+    }
+
+    pub fn raw_details(mut self, details: &str) -> Self {
+        self.raw_details = Some(details.to_string());
+        self
+    }
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct Image {
+    pub alt: String,
+    pub image_url: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption: Option<String>,
+}
+
+/// A button shown on the check run that sends a `requested_action` webhook
+/// with `identifier` when clicked
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct CheckRunAction {
+    pub label: String,
+    pub description: String,
+    pub identifier: String,
+}
+
+/// The body of `GET /repos/:owner/:repo/commits/:ref/check-runs` and
+/// `GET /repos/:owner/:repo/check-suites/:id/check-runs`. `T` is usually
+/// `webhooks::CheckRun` or `serde_json::Value`.
+#[derive(Debug, Clone, Deserialize)]
+pub struct CheckRunList<T = Value> {
+    pub total_count: u64,
+    pub check_runs: Vec<T>,
+}
+
+/// The body of `GET /repos/:owner/:repo/commits/:ref/check-suites`. `T` is
+/// usually `webhooks::CheckSuite` or `serde_json::Value`.
+#[derive(Debug, Clone, Deserialize)]
+pub struct CheckSuiteList<T = Value> {
+    pub total_count: u64,
+    pub check_suites: Vec<T>,
+}
+
+/// Body for `PATCH /repos/:owner/:repo/check-suites/preferences`
+#[derive(Debug, Clone, Default, Serialize, PartialEq)]
+pub struct CheckSuitePreferences {
+    pub auto_trigger_checks: Vec<AutoTriggerCheck>,
+}
+
+impl CheckSuitePreferences {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Turn automatic check suite creation on or off for a GitHub App
+    pub fn auto_trigger(mut self, app_id: u64, setting: bool) -> Self {
+        self.auto_trigger_checks
+            .push(AutoTriggerCheck { app_id, setting });
+        self
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct AutoTriggerCheck {
+    pub app_id: u64,
+    pub setting: bool,
+}
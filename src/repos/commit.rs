@@ -0,0 +1,332 @@
+//! Make a single commit touching several files through the Git Data API
+//!
+//! ```text
+//! let sha = CommitBuilder::new("owner", "repo", "main", "Sync config")
+//!     .add("config/app.toml", b"debug = false\n")
+//!     .add("config/db.toml", b"pool = 8\n")
+//!     .add_with_mode("bin/deploy.sh", b"#!/bin/sh\n", FileMode::Executable)
+//!     .delete("config/old.toml")
+//!     .committer("sync-bot", "sync-bot@example.com")
+//!     .execute(&g)?;
+//! ```
+use crate::client::{Executor, Github};
+use crate::errors::*;
+use crate::repos::contents::{encode_content, CommitIdentity};
+use hyper::HeaderMap;
+use hyper::StatusCode;
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+
+/// Builds a commit out of a set of file changes and moves a branch to it.
+///
+/// Executing it gets the head of the branch, uploads a blob for each added
+/// file, creates a tree on top of the head's tree, creates the commit and
+/// then fast-forwards the branch. If the branch moved in the meantime GitHub
+/// rejects the update and the commit is rebuilt on the new head, up to
+/// `retries` times. Blobs are only uploaded once.
+///
+/// The request bodies for each step are available through `blobs`, `tree`
+/// and `commit` for anyone driving the Git Data API themselves.
+#[derive(Debug, Clone)]
+pub struct CommitBuilder {
+    owner: String,
+    repo: String,
+    branch: String,
+    message: String,
+    changes: Vec<Change>,
+    author: Option<CommitIdentity>,
+    committer: Option<CommitIdentity>,
+    retries: usize,
+}
+
+#[derive(Debug, Clone)]
+struct Change {
+    path: String,
+    mode: FileMode,
+    /// `None` deletes the path
+    content: Option<Vec<u8>>,
+}
+
+/// The mode of a file in a git tree
+#[derive(Debug, Clone, Copy, Serialize, PartialEq)]
+pub enum FileMode {
+    #[serde(rename = "100644")]
+    File,
+    #[serde(rename = "100755")]
+    Executable,
+    /// The content of a symlink is the path it points to
+    #[serde(rename = "120000")]
+    Symlink,
+}
+
+/// Body for `POST /repos/:owner/:repo/git/blobs`
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct CreateBlob {
+    pub content: String,
+    pub encoding: String,
+}
+
+/// Body for `POST /repos/:owner/:repo/git/trees`
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct CreateTree {
+    pub base_tree: String,
+    pub tree: Vec<TreeEntry>,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct TreeEntry {
+    pub path: String,
+    pub mode: FileMode,
+    #[serde(rename = "type")]
+    pub kind: String,
+    /// `None` deletes the path from the base tree, so it's always sent
+    pub sha: Option<String>,
+}
+
+/// Body for `POST /repos/:owner/:repo/git/commits`
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct CreateCommit {
+    pub message: String,
+    pub tree: String,
+    pub parents: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub author: Option<CommitIdentity>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub committer: Option<CommitIdentity>,
+}
+
+#[derive(Serialize)]
+struct UpdateRef {
+    sha: String,
+    force: bool,
+}
+
+#[derive(Deserialize)]
+struct Object {
+    sha: String,
+}
+
+#[derive(Deserialize)]
+struct Ref {
+    object: Object,
+}
+
+#[derive(Deserialize)]
+struct CommitObject {
+    tree: Object,
+}
+
+impl CommitBuilder {
+    /// Commit to `branch` of `owner/repo` with the given message
+    pub fn new(owner: &str, repo: &str, branch: &str, message: &str) -> Self {
+        CommitBuilder {
+            owner: owner.to_string(),
+            repo: repo.to_string(),
+            branch: branch.to_string(),
+            message: message.to_string(),
+            changes: Vec::new(),
+            author: None,
+            committer: None,
+            retries: 3,
+        }
+    }
+
+    /// Create or replace the file at `path` as a regular, non executable
+    /// file. Use `add_with_mode` to keep a script executable.
+    pub fn add(self, path: &str, content: &[u8]) -> Self {
+        self.add_with_mode(path, content, FileMode::File)
+    }
+
+    /// Create or replace the file at `path` with the given mode
+    pub fn add_with_mode(mut self, path: &str, content: &[u8], mode: FileMode) -> Self {
+        self.changes.push(Change {
+            path: path.to_string(),
+            mode,
+            content: Some(content.to_vec()),
+        });
+        self
+    }
+
+    /// Remove the file at `path`
+    pub fn delete(mut self, path: &str) -> Self {
+        self.changes.push(Change {
+            path: path.to_string(),
+            mode: FileMode::File,
+            content: None,
+        });
+        self
+    }
+
+    pub fn author(mut self, name: &str, email: &str) -> Self {
+        self.author = Some(CommitIdentity::new(name, email));
+        self
+    }
+
+    pub fn committer(mut self, name: &str, email: &str) -> Self {
+        self.committer = Some(CommitIdentity::new(name, email));
+        self
+    }
+
+    /// How many times to rebuild the commit when the branch moves before it
+    /// can be updated. Defaults to 3.
+    pub fn retries(mut self, retries: usize) -> Self {
+        self.retries = retries;
+        self
+    }
+
+    /// The blob to upload for each added file, in the order they were added
+    pub fn blobs(&self) -> Vec<CreateBlob> {
+        self.changes
+            .iter()
+            .filter_map(|change| change.content.as_ref())
+            .map(|content| CreateBlob {
+                content: encode_content(content),
+                encoding: "base64".to_string(),
+            })
+            .collect()
+    }
+
+    /// The tree to create on top of `base_tree`. `blob_shas` holds the sha of
+    /// each blob from `blobs`, in the same order.
+    pub fn tree(&self, base_tree: &str, blob_shas: &[String]) -> Result<CreateTree> {
+        let mut shas = blob_shas.iter();
+        let mut tree = Vec::with_capacity(self.changes.len());
+        for change in &self.changes {
+            let sha = match change.content {
+                Some(_) => match shas.next() {
+                    Some(sha) => Some(sha.clone()),
+                    None => return Err(format!("No blob sha for {}", change.path).into()),
+                },
+                None => None,
+            };
+            tree.push(TreeEntry {
+                path: change.path.clone(),
+                mode: change.mode,
+                kind: "blob".to_string(),
+                sha,
+            });
+        }
+        if shas.next().is_some() {
+            return Err("More blob shas than added files".into());
+        }
+        Ok(CreateTree {
+            base_tree: base_tree.to_string(),
+            tree,
+        })
+    }
+
+    /// The commit of `tree` on top of `parent`
+    pub fn commit(&self, tree: &str, parent: &str) -> CreateCommit {
+        CreateCommit {
+            message: self.message.clone(),
+            tree: tree.to_string(),
+            parents: vec![parent.to_string()],
+            author: self.author.clone(),
+            committer: self.committer.clone(),
+        }
+    }
+
+    /// Make the commit, returning its sha
+    pub fn execute(&self, g: &Github) -> Result<String> {
+        let mut blob_shas = Vec::new();
+        for blob in self.blobs() {
+            let res = g
+                .post(blob)
+                .repos()
+                .owner(&self.owner)
+                .repo(&self.repo)
+                .git()
+                .blobs()
+                .execute::<Value>();
+            blob_shas.push(expect::<Object>(res, "create blob")?.sha);
+        }
+
+        let branch = format!("heads/{}", self.branch);
+        let mut attempt = 0;
+        loop {
+            let res = g
+                .get()
+                .repos()
+                .owner(&self.owner)
+                .repo(&self.repo)
+                .git()
+                .git_ref()
+                .reference(&branch)
+                .execute::<Value>();
+            let head = expect::<Ref>(res, "get branch")?.object.sha;
+
+            let res = g
+                .get()
+                .repos()
+                .owner(&self.owner)
+                .repo(&self.repo)
+                .git()
+                .commits()
+                .sha(&head)
+                .execute::<Value>();
+            let base_tree = expect::<CommitObject>(res, "get head commit")?.tree.sha;
+
+            let res = g
+                .post(self.tree(&base_tree, &blob_shas)?)
+                .repos()
+                .owner(&self.owner)
+                .repo(&self.repo)
+                .git()
+                .trees()
+                .execute::<Value>();
+            let tree = expect::<Object>(res, "create tree")?.sha;
+
+            let res = g
+                .post(self.commit(&tree, &head))
+                .repos()
+                .owner(&self.owner)
+                .repo(&self.repo)
+                .git()
+                .commits()
+                .execute::<Value>();
+            let commit = expect::<Object>(res, "create commit")?.sha;
+
+            let update = UpdateRef {
+                sha: commit.clone(),
+                force: false,
+            };
+            let (_, status, _) = g
+                .patch(update)
+                .repos()
+                .owner(&self.owner)
+                .repo(&self.repo)
+                .git()
+                .refs()
+                .reference(&branch)
+                .execute::<Value>()?;
+            if status.is_success() {
+                return Ok(commit);
+            }
+            // GitHub answers a non fast forward update with 422
+            if status != StatusCode::UNPROCESSABLE_ENTITY || attempt >= self.retries {
+                return Err(format!("Unable to update branch {}: {}", self.branch, status).into());
+            }
+            attempt += 1;
+        }
+    }
+}
+
+/// Check the status of a step before reading its body, so an error response
+/// like a missing branch is reported as such rather than as a body that
+/// doesn't match `T`
+fn expect<T>(res: Result<(HeaderMap, StatusCode, Option<Value>)>, step: &str) -> Result<T>
+where
+    T: DeserializeOwned,
+{
+    match res? {
+        (_, status, Some(body)) if status.is_success() => Ok(serde_json::from_value(body)?),
+        (_, status, body) => {
+            let message = body
+                .as_ref()
+                .and_then(|b| b["message"].as_str())
+                .map(|m| format!(" ({})", m))
+                .unwrap_or_default();
+            Err(format!("Unable to {}: {}{}", step, status, message).into())
+        }
+    }
+}
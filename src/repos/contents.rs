@@ -0,0 +1,181 @@
+//! Bodies for writing files through the Contents API
+//!
+//! GitHub expects file contents to be base64 encoded and updates or deletes
+//! to carry the blob `sha` of the file being replaced:
+//!
+//! ```text
+//! let (_, _, file) = g.get().repos().owner("o").repo("r")
+//!     .contents().path("config.toml")
+//!     .execute::<FileContent>()?;
+//! let file = file.unwrap();
+//! let old = file.decode()?;
+//!
+//! let update = UpdateFile::new("Update config", b"new = true\n")
+//!     .sha(&file.sha)
+//!     .branch("main")
+//!     .committer("sync-bot", "sync-bot@example.com");
+//! g.put(update).repos().owner("o").repo("r")
+//!     .contents().path("config.toml")
+//!     .execute::<Value>()?;
+//! ```
+use crate::errors::*;
+
+/// Encode raw file contents the way the Contents API expects them
+pub fn encode_content(content: &[u8]) -> String {
+    base64::encode(content)
+}
+
+/// Decode the `content` of a file returned by the Contents API. GitHub wraps
+/// the base64 at 60 characters so any whitespace is skipped.
+pub fn decode_content(content: &str) -> Result<Vec<u8>> {
+    let content: String = content.split_whitespace().collect();
+    Ok(base64::decode(&content)?)
+}
+
+/// A file returned by `GET /repos/:owner/:repo/contents/:path`
+#[derive(Debug, Clone, Deserialize)]
+pub struct FileContent {
+    #[serde(rename = "type")]
+    pub kind: String,
+    pub encoding: Option<String>,
+    pub size: u64,
+    pub name: String,
+    pub path: String,
+    /// The blob sha, needed to update or delete the file
+    pub sha: String,
+    pub content: Option<String>,
+    pub html_url: Option<String>,
+    pub download_url: Option<String>,
+}
+
+impl FileContent {
+    /// The decoded contents of the file. Files over 1MB are returned without
+    /// their contents (`encoding` is `none`), which is an error rather than
+    /// an empty file. Fetch those through `download_url` or the Git blobs API.
+    pub fn decode(&self) -> Result<Vec<u8>> {
+        match (self.encoding.as_deref(), &self.content) {
+            (Some("base64"), Some(content)) => decode_content(content),
+            (encoding, _) => Err(format!(
+                "{} was returned without base64 content (encoding: {}), \
+                 it may be too large for the Contents API",
+                self.path,
+                encoding.unwrap_or("missing")
+            )
+            .into()),
+        }
+    }
+}
+
+/// The name and email a commit is attributed to
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct CommitIdentity {
+    pub name: String,
+    pub email: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub date: Option<String>,
+}
+
+impl CommitIdentity {
+    pub fn new(name: &str, email: &str) -> Self {
+        CommitIdentity {
+            name: name.to_string(),
+            email: email.to_string(),
+            date: None,
+        }
+    }
+}
+
+/// Body for `PUT /repos/:owner/:repo/contents/:path`, which creates a file or
+/// replaces it when `sha` is set
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct UpdateFile {
+    pub message: String,
+    pub content: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sha: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub branch: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub committer: Option<CommitIdentity>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub author: Option<CommitIdentity>,
+}
+
+impl UpdateFile {
+    /// Commit `content` with the given message. The content is base64
+    /// encoded for you.
+    pub fn new(message: &str, content: &[u8]) -> Self {
+        UpdateFile {
+            message: message.to_string(),
+            content: encode_content(content),
+            sha: None,
+            branch: None,
+            committer: None,
+            author: None,
+        }
+    }
+
+    /// The blob sha of the file being replaced, required for updates
+    pub fn sha(mut self, sha: &str) -> Self {
+        self.sha = Some(sha.to_string());
+        self
+    }
+
+    /// Commit to this branch instead of the default branch
+    pub fn branch(mut self, branch: &str) -> Self {
+        self.branch = Some(branch.to_string());
+        self
+    }
+
+    pub fn committer(mut self, name: &str, email: &str) -> Self {
+        self.committer = Some(CommitIdentity::new(name, email));
+        self
+    }
+
+    pub fn author(mut self, name: &str, email: &str) -> Self {
+        self.author = Some(CommitIdentity::new(name, email));
+        self
+    }
+}
+
+/// Body for `DELETE /repos/:owner/:repo/contents/:path`
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct DeleteFile {
+    pub message: String,
+    pub sha: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub branch: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub committer: Option<CommitIdentity>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub author: Option<CommitIdentity>,
+}
+
+impl DeleteFile {
+    /// Delete the file with the blob `sha`
+    pub fn new(message: &str, sha: &str) -> Self {
+        DeleteFile {
+            message: message.to_string(),
+            sha: sha.to_string(),
+            branch: None,
+            committer: None,
+            author: None,
+        }
+    }
+
+    /// Commit to this branch instead of the default branch
+    pub fn branch(mut self, branch: &str) -> Self {
+        self.branch = Some(branch.to_string());
+        self
+    }
+
+    pub fn committer(mut self, name: &str, email: &str) -> Self {
+        self.committer = Some(CommitIdentity::new(name, email));
+        self
+    }
+
+    pub fn author(mut self, name: &str, email: &str) -> Self {
+        self.author = Some(CommitIdentity::new(name, email));
+        self
+    }
+}
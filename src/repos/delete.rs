@@ -1 +1,296 @@
+//! Access the Repos portion of the GitHub API
+imports!();
+use crate::client::DeleteQueryBuilder;
 
+new_type!(
+    Branches
+    BranchesName
+    BranchesNameProtection
+    BranchesNameProtectionEnforceAdmins
+    BranchesNameProtectionRequiredPullRequestReviews
+    BranchesNameProtectionRequiredSignatures
+    BranchesNameProtectionRequiredStatusChecks
+    BranchesNameProtectionRequiredStatusChecksContexts
+    BranchesNameProtectionRestrictions
+    BranchesNameProtectionRestrictionsApps
+    BranchesNameProtectionRestrictionsTeams
+    BranchesNameProtectionRestrictionsUsers
+    Collaborators
+    CollaboratorsUsername
+    Contents
+    ContentsPath
+    Deployments
+    DeploymentsId
+    Environments
+    EnvironmentsName
+    Git
+    GitRefs
+    GitRefsReference
+    Hooks
+    HooksId
+    Invitations
+    InvitationsId
+    Issues
+    IssuesComments
+    IssuesCommentsId
+    IssuesNumber
+    IssuesNumberAssignees
+    IssuesNumberLabels
+    IssuesNumberLabelsName
+    IssuesNumberLock
+    Labels
+    LabelsName
+    Milestones
+    MilestonesNumber
+    Owner
+    Pulls
+    PullsComments
+    PullsCommentsId
+    PullsNumber
+    PullsNumberRequestedReviewers
+    PullsNumberReviews
+    PullsNumberReviewsId
+    Releases
+    ReleasesAssets
+    ReleasesAssetsId
+    ReleasesId
+    Repo
+    Repos
+    Rulesets
+    RulesetsId
+);
+
+from!(
+    @DeleteQueryBuilder
+        -> Repos = "repos"
+    @Repos
+        => Owner
+    @Owner
+        => Repo
+    @Repo
+        -> Issues = "issues"
+        -> Labels = "labels"
+        -> Milestones = "milestones"
+        -> Pulls = "pulls"
+        -> Contents = "contents"
+        -> Git = "git"
+        -> Releases = "releases"
+        -> Branches = "branches"
+        -> Rulesets = "rulesets"
+        -> Collaborators = "collaborators"
+        -> Invitations = "invitations"
+        -> Hooks = "hooks"
+        -> Deployments = "deployments"
+        -> Environments = "environments"
+    @Issues
+        => IssuesNumber
+        -> IssuesComments = "comments"
+    @IssuesComments
+        => IssuesCommentsId
+    @IssuesNumber
+        -> IssuesNumberLock = "lock"
+        -> IssuesNumberLabels = "labels"
+        -> IssuesNumberAssignees = "assignees"
+    @IssuesNumberLabels
+        => IssuesNumberLabelsName
+    @Labels
+        => LabelsName
+    @Milestones
+        => MilestonesNumber
+    @Pulls
+        => PullsNumber
+        -> PullsComments = "comments"
+    @PullsNumber
+        -> PullsNumberReviews = "reviews"
+        -> PullsNumberRequestedReviewers = "requested_reviewers"
+    @PullsNumberReviews
+        => PullsNumberReviewsId
+    @PullsComments
+        => PullsCommentsId
+    @Contents
+        => ContentsPath
+    @Git
+        -> GitRefs = "refs"
+    @GitRefs
+        => GitRefsReference
+    @Releases
+        => ReleasesId
+        -> ReleasesAssets = "assets"
+    @ReleasesAssets
+        => ReleasesAssetsId
+    @Branches
+        => BranchesName
+    @BranchesName
+        -> BranchesNameProtection = "protection"
+    @BranchesNameProtection
+        -> BranchesNameProtectionEnforceAdmins = "enforce_admins"
+        -> BranchesNameProtectionRequiredSignatures = "required_signatures"
+        -> BranchesNameProtectionRequiredStatusChecks = "required_status_checks"
+        -> BranchesNameProtectionRequiredPullRequestReviews = "required_pull_request_reviews"
+        -> BranchesNameProtectionRestrictions = "restrictions"
+    @BranchesNameProtectionRequiredStatusChecks
+        -> BranchesNameProtectionRequiredStatusChecksContexts = "contexts"
+    @BranchesNameProtectionRestrictions
+        -> BranchesNameProtectionRestrictionsUsers = "users"
+        -> BranchesNameProtectionRestrictionsTeams = "teams"
+        -> BranchesNameProtectionRestrictionsApps = "apps"
+    @Rulesets
+        => RulesetsId
+    @Collaborators
+        => CollaboratorsUsername
+    @Invitations
+        => InvitationsId
+    @Hooks
+        => HooksId
+    @Deployments
+        => DeploymentsId
+    @Environments
+        => EnvironmentsName
+);
+
+impl_macro!(
+    @Repos
+        |
+        |=> owner -> Owner = username_str
+    @Owner
+        |
+        |=> repo -> Repo = repo_str
+    @Repo
+        |=> issues -> Issues
+        |=> labels -> Labels
+        |=> milestones -> Milestones
+        |=> pulls -> Pulls
+        |=> contents -> Contents
+        |=> git -> Git
+        |=> releases -> Releases
+        |=> branches -> Branches
+        |=> rulesets -> Rulesets
+        |=> collaborators -> Collaborators
+        |=> invitations -> Invitations
+        |=> hooks -> Hooks
+        |=> deployments -> Deployments
+        |=> environments -> Environments
+        |
+    @Issues
+        |=> comments -> IssuesComments
+        |
+        |=> number -> IssuesNumber = issue_number
+    @IssuesComments
+        |
+        |=> id -> IssuesCommentsId = comment_id
+    @IssuesNumber
+        |=> lock -> IssuesNumberLock
+        |=> labels -> IssuesNumberLabels
+        |=> assignees -> IssuesNumberAssignees
+        |
+    @IssuesNumberLabels
+        |
+        |/> name -> IssuesNumberLabelsName = label_name
+    @Labels
+        |
+        |/> name -> LabelsName = label_name
+    @Milestones
+        |
+        |=> number -> MilestonesNumber = milestone_number
+    @Pulls
+        |=> comments -> PullsComments
+        |
+        |=> number -> PullsNumber = number_str
+    @PullsNumber
+        |=> reviews -> PullsNumberReviews
+        |=> requested_reviewers -> PullsNumberRequestedReviewers
+        |
+    @PullsNumberReviews
+        |
+        |=> id -> PullsNumberReviewsId = review_id
+    @PullsComments
+        |
+        |=> id -> PullsCommentsId = id_str
+    @Contents
+        |
+        |=> path -> ContentsPath = path_str
+    @Git
+        |=> refs -> GitRefs
+        |
+    @GitRefs
+        |
+        |=> reference -> GitRefsReference = ref_str
+    @Releases
+        |=> assets -> ReleasesAssets
+        |
+        |=> id -> ReleasesId = release_id
+    @ReleasesAssets
+        |
+        |=> id -> ReleasesAssetsId = asset_id
+    @Branches
+        |
+        |=> name -> BranchesName = name
+    @BranchesName
+        |=> protection -> BranchesNameProtection
+        |
+    @BranchesNameProtection
+        |=> enforce_admins -> BranchesNameProtectionEnforceAdmins
+        |=> required_signatures -> BranchesNameProtectionRequiredSignatures
+        |=> required_status_checks -> BranchesNameProtectionRequiredStatusChecks
+        |=> required_pull_request_reviews -> BranchesNameProtectionRequiredPullRequestReviews
+        |=> restrictions -> BranchesNameProtectionRestrictions
+        |
+    @BranchesNameProtectionRequiredStatusChecks
+        |=> contexts -> BranchesNameProtectionRequiredStatusChecksContexts
+        |
+    @BranchesNameProtectionRestrictions
+        |=> users -> BranchesNameProtectionRestrictionsUsers
+        |=> teams -> BranchesNameProtectionRestrictionsTeams
+        |=> apps -> BranchesNameProtectionRestrictionsApps
+        |
+    @Rulesets
+        |
+        |=> id -> RulesetsId = ruleset_id
+    @Collaborators
+        |
+        |=> username -> CollaboratorsUsername = username
+    @Invitations
+        |
+        |=> id -> InvitationsId = invitation_id
+    @Hooks
+        |
+        |=> id -> HooksId = hook_id
+    @Deployments
+        |
+        |=> id -> DeploymentsId = deployment_id
+    @Environments
+        |
+        |/> name -> EnvironmentsName = environment_name
+);
+
+exec!(BranchesNameProtection);
+exec!(BranchesNameProtectionEnforceAdmins);
+exec!(BranchesNameProtectionRequiredPullRequestReviews);
+exec!(BranchesNameProtectionRequiredSignatures);
+exec!(BranchesNameProtectionRequiredStatusChecks);
+exec!(BranchesNameProtectionRequiredStatusChecksContexts);
+exec!(BranchesNameProtectionRestrictions);
+exec!(BranchesNameProtectionRestrictionsApps);
+exec!(BranchesNameProtectionRestrictionsTeams);
+exec!(BranchesNameProtectionRestrictionsUsers);
+exec!(CollaboratorsUsername);
+exec!(ContentsPath);
+exec!(DeploymentsId);
+exec!(EnvironmentsName);
+exec!(GitRefsReference);
+exec!(HooksId);
+exec!(InvitationsId);
+exec!(IssuesCommentsId);
+exec!(IssuesNumberAssignees);
+exec!(IssuesNumberLabels);
+exec!(IssuesNumberLabelsName);
+exec!(IssuesNumberLock);
+exec!(LabelsName);
+exec!(MilestonesNumber);
+exec!(PullsCommentsId);
+exec!(PullsNumberRequestedReviewers);
+exec!(PullsNumberReviewsId);
+exec!(ReleasesAssetsId);
+exec!(ReleasesId);
+exec!(Repo);
+exec!(RulesetsId);
@@ -0,0 +1,293 @@
+//! Typed bodies for deployments, deployment statuses and environments
+//!
+//! ```text
+//! let (_, _, deployment) = g
+//!     .post(CreateDeployment::new("v1.2.0").environment("production"))
+//!     .repos().owner("o").repo("r")
+//!     .deployments()
+//!     .execute::<Deployment>()?;
+//! let id = deployment.unwrap().id.to_string();
+//! g.post(CreateDeploymentStatus::new("success").log_url("https://ci.example.com/42"))
+//!     .repos().owner("o").repo("r")
+//!     .deployments().id(&id).statuses()
+//!     .execute::<DeploymentStatus>()?;
+//! ```
+use crate::models::User;
+use serde_json::Value;
+
+/// Body for `POST /repos/:owner/:repo/deployments`
+#[derive(Debug, Clone, Default, Serialize, PartialEq)]
+pub struct CreateDeployment {
+    /// The branch, tag or sha to deploy
+    #[serde(rename = "ref")]
+    pub git_ref: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub task: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub auto_merge: Option<bool>,
+    /// The status contexts that must pass before deploying. GitHub checks
+    /// every context when this is left unset, an empty list skips the check.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub required_contexts: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payload: Option<Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub environment: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub transient_environment: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub production_environment: Option<bool>,
+}
+
+impl CreateDeployment {
+    pub fn new(git_ref: &str) -> Self {
+        CreateDeployment {
+            git_ref: git_ref.to_string(),
+            ..Default::default()
+        }
+    }
+
+    pub fn task(mut self, task: &str) -> Self {
+        self.task = Some(task.to_string());
+        self
+    }
+
+    pub fn auto_merge(mut self, auto_merge: bool) -> Self {
+        self.auto_merge = Some(auto_merge);
+        self
+    }
+
+    pub fn required_contexts(mut self, contexts: &[&str]) -> Self {
+        self.required_contexts = Some(contexts.iter().map(|c| c.to_string()).collect());
+        self
+    }
+
+    /// Extra JSON for the systems listening for deployment events
+    pub fn payload(mut self, payload: Value) -> Self {
+        self.payload = Some(payload);
+        self
+    }
+
+    pub fn environment(mut self, environment: &str) -> Self {
+        self.environment = Some(environment.to_string());
+        self
+    }
+
+    pub fn description(mut self, description: &str) -> Self {
+        self.description = Some(description.to_string());
+        self
+    }
+
+    pub fn transient_environment(mut self, transient: bool) -> Self {
+        self.transient_environment = Some(transient);
+        self
+    }
+
+    pub fn production_environment(mut self, production: bool) -> Self {
+        self.production_environment = Some(production);
+        self
+    }
+}
+
+/// A deployment returned by the deployments endpoints
+#[derive(Debug, Clone, Deserialize)]
+pub struct Deployment {
+    pub id: u64,
+    pub node_id: Option<String>,
+    pub sha: String,
+    #[serde(rename = "ref")]
+    pub git_ref: String,
+    pub task: String,
+    #[serde(default)]
+    pub payload: Value,
+    pub environment: String,
+    pub original_environment: Option<String>,
+    pub description: Option<String>,
+    pub creator: Option<User>,
+    pub created_at: String,
+    pub updated_at: String,
+    pub statuses_url: Option<String>,
+    pub transient_environment: Option<bool>,
+    pub production_environment: Option<bool>,
+}
+
+/// Body for `POST /repos/:owner/:repo/deployments/:id/statuses`
+#[derive(Debug, Clone, Default, Serialize, PartialEq)]
+pub struct CreateDeploymentStatus {
+    /// `error`, `failure`, `inactive`, `in_progress`, `queued`, `pending` or
+    /// `success`
+    pub state: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub log_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub environment: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub environment_url: Option<String>,
+    /// Mark earlier successful deployments to the environment as inactive
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub auto_inactive: Option<bool>,
+}
+
+impl CreateDeploymentStatus {
+    pub fn new(state: &str) -> Self {
+        CreateDeploymentStatus {
+            state: state.to_string(),
+            ..Default::default()
+        }
+    }
+
+    pub fn log_url(mut self, url: &str) -> Self {
+        self.log_url = Some(url.to_string());
+        self
+    }
+
+    pub fn description(mut self, description: &str) -> Self {
+        self.description = Some(description.to_string());
+        self
+    }
+
+    pub fn environment(mut self, environment: &str) -> Self {
+        self.environment = Some(environment.to_string());
+        self
+    }
+
+    pub fn environment_url(mut self, url: &str) -> Self {
+        self.environment_url = Some(url.to_string());
+        self
+    }
+
+    pub fn auto_inactive(mut self, auto_inactive: bool) -> Self {
+        self.auto_inactive = Some(auto_inactive);
+        self
+    }
+}
+
+/// A status returned by the deployment statuses endpoints
+#[derive(Debug, Clone, Deserialize)]
+pub struct DeploymentStatus {
+    pub id: u64,
+    pub node_id: Option<String>,
+    pub state: String,
+    pub description: Option<String>,
+    pub environment: Option<String>,
+    pub target_url: Option<String>,
+    pub log_url: Option<String>,
+    pub environment_url: Option<String>,
+    pub creator: Option<User>,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+/// Body for `PUT /repos/:owner/:repo/environments/:name`, which creates an
+/// environment or updates its protection rules
+#[derive(Debug, Clone, Default, Serialize, PartialEq)]
+pub struct UpdateEnvironment {
+    /// Minutes to wait before a deployment may go ahead, up to 43,200
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub wait_timer: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub prevent_self_review: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reviewers: Option<Vec<EnvironmentReviewer>>,
+    /// `Some(None)` is sent as `null`, which lets every branch deploy again
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub deployment_branch_policy: Option<Option<DeploymentBranchPolicy>>,
+}
+
+impl UpdateEnvironment {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn wait_timer(mut self, minutes: u32) -> Self {
+        self.wait_timer = Some(minutes);
+        self
+    }
+
+    pub fn prevent_self_review(mut self, prevent: bool) -> Self {
+        self.prevent_self_review = Some(prevent);
+        self
+    }
+
+    /// Require a review from this user before deploying
+    pub fn user_reviewer(self, id: u64) -> Self {
+        self.reviewer("User", id)
+    }
+
+    /// Require a review from someone on this team before deploying
+    pub fn team_reviewer(self, id: u64) -> Self {
+        self.reviewer("Team", id)
+    }
+
+    pub fn deployment_branch_policy(mut self, policy: DeploymentBranchPolicy) -> Self {
+        self.deployment_branch_policy = Some(Some(policy));
+        self
+    }
+
+    /// Remove the branch policy so any branch can deploy to the environment
+    pub fn allow_all_branches(mut self) -> Self {
+        self.deployment_branch_policy = Some(None);
+        self
+    }
+
+    fn reviewer(mut self, kind: &str, id: u64) -> Self {
+        self.reviewers
+            .get_or_insert_with(Vec::new)
+            .push(EnvironmentReviewer {
+                kind: kind.to_string(),
+                id,
+            });
+        self
+    }
+}
+
+/// A user or team who can approve deployments to an environment
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct EnvironmentReviewer {
+    /// `User` or `Team`
+    #[serde(rename = "type")]
+    pub kind: String,
+    pub id: u64,
+}
+
+/// Which branches can deploy to an environment. Only one of the two can be
+/// set.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct DeploymentBranchPolicy {
+    /// Only branches with branch protection rules
+    pub protected_branches: bool,
+    /// Only branches matching the environment's branch name patterns
+    pub custom_branch_policies: bool,
+}
+
+/// An environment returned by the environments endpoints
+#[derive(Debug, Clone, Deserialize)]
+pub struct Environment {
+    pub id: u64,
+    pub node_id: Option<String>,
+    pub name: String,
+    pub html_url: Option<String>,
+    pub created_at: String,
+    pub updated_at: String,
+    #[serde(default)]
+    pub protection_rules: Vec<ProtectionRule>,
+    pub deployment_branch_policy: Option<DeploymentBranchPolicy>,
+}
+
+/// One of the protection rules on an environment
+#[derive(Debug, Clone, Deserialize)]
+pub struct ProtectionRule {
+    pub id: u64,
+    /// `wait_timer`, `required_reviewers` or `branch_policy`
+    #[serde(rename = "type")]
+    pub kind: String,
+    pub wait_timer: Option<u32>,
+    pub prevent_self_review: Option<bool>,
+    /// Each reviewer's `type` and the `reviewer` user or team
+    #[serde(default)]
+    pub reviewers: Vec<Value>,
+}
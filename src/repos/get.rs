@@ -9,11 +9,21 @@ new_type!(
     Branches
     BranchesName
     BranchesNameProtection
+    BranchesNameProtectionEnforceAdmins
     BranchesNameProtectionRestrictions
+    BranchesNameProtectionRestrictionsApps
+    BranchesNameProtectionRestrictionsTeams
     BranchesNameProtectionRestrictionsUsers
     BranchesNameProtectionRequiredPullRequestReviews
+    BranchesNameProtectionRequiredSignatures
     BranchesNameProtectionRequiredStatusChecks
     BranchesNameProtectionRequiredStatusChecksContexts
+    CheckRuns
+    CheckRunsId
+    CheckRunsIdAnnotations
+    CheckSuites
+    CheckSuitesId
+    CheckSuitesIdCheckRuns
     Collaborators
     CollaboratorsUsername
     CollaboratorsUsernamePermission
@@ -23,19 +33,56 @@ new_type!(
     CommitsStatus
     CommitsStatuses
     Commits
+    CommitsCheckRuns
+    CommitsCheckSuites
     Contents
     ContentsPath
     ContentsReference
     Contributors
+    Deployments
+    DeploymentsId
+    DeploymentsIdStatuses
+    DeploymentsIdStatusesId
+    Environments
+    EnvironmentsName
     Events
     Forks
+    Git
+    GitBlobs
+    GitBlobsSha
+    GitCommits
+    GitCommitsSha
+    GitMatchingRefs
+    GitMatchingRefsReference
+    GitRef
+    GitRefReference
+    GitTags
+    GitTagsSha
+    GitTrees
+    GitTreesSha
+    Hooks
+    HooksId
+    HooksIdConfig
+    HooksIdDeliveries
+    HooksIdDeliveriesId
+    Invitations
     Issues
     IssuesState
     IssuesComments
     IssuesCommentsId
+    IssuesEvents
+    IssuesEventsId
     IssuesNumber
     IssuesNumberComments
+    IssuesNumberEvents
+    IssuesNumberLabels
+    IssuesNumberTimeline
+    Labels
+    LabelsName
     Languages
+    Milestones
+    MilestonesNumber
+    MilestonesNumberLabels
     Notifications
     Owner
     Pulls
@@ -47,9 +94,25 @@ new_type!(
     PullsNumberFiles
     PullsNumberRequestedReviewers
     PullsNumberMerge
+    PullsNumberReviews
+    PullsNumberReviewsId
+    PullsNumberReviewsIdComments
     Readme
+    Releases
+    ReleasesAssets
+    ReleasesAssetsId
+    ReleasesId
+    ReleasesIdAssets
+    ReleasesLatest
+    ReleasesTags
+    ReleasesTagsTag
     Repo
     Repos
+    Rules
+    RulesBranches
+    RulesBranchesBranch
+    Rulesets
+    RulesetsId
     Stargazers
     Subscribers
     Subscription
@@ -70,8 +133,12 @@ from!(
        -> BranchesNameProtectionRestrictions = "restrictions"
        -> BranchesNameProtectionRequiredPullRequestReviews = "required_pull_request_reviews"
        -> BranchesNameProtectionRequiredStatusChecks = "required_status_checks"
+       -> BranchesNameProtectionEnforceAdmins = "enforce_admins"
+       -> BranchesNameProtectionRequiredSignatures = "required_signatures"
     @BranchesNameProtectionRestrictions
        -> BranchesNameProtectionRestrictionsUsers = "users"
+       -> BranchesNameProtectionRestrictionsTeams = "teams"
+       -> BranchesNameProtectionRestrictionsApps = "apps"
     @BranchesNameProtectionRequiredStatusChecks
        -> BranchesNameProtectionRequiredStatusChecksContexts = "contexts"
 
@@ -85,12 +152,20 @@ from!(
        -> CommitsStatus = "status"
     @CommitsSha
        -> CommitsStatuses = "statuses"
+    @CommitsSha
+       -> CommitsCheckRuns = "check-runs"
+    @CommitsSha
+       -> CommitsCheckSuites = "check-suites"
     @CommitsReference
        -> CommitsComments = "comments"
     @CommitsReference
        -> CommitsStatus = "status"
     @CommitsReference
        -> CommitsStatuses = "statuses"
+    @CommitsReference
+       -> CommitsCheckRuns = "check-runs"
+    @CommitsReference
+       -> CommitsCheckSuites = "check-suites"
     @Commits
        => CommitsSha
        => CommitsReference
@@ -104,10 +179,17 @@ from!(
        -> IssuesComments = "comments"
     @Issues
        => IssuesNumber
+    @Issues
+       -> IssuesEvents = "events"
     @IssuesComments
        => IssuesCommentsId
+    @IssuesEvents
+       => IssuesEventsId
     @IssuesNumber
        -> IssuesNumberComments = "comments"
+       -> IssuesNumberEvents = "events"
+       -> IssuesNumberTimeline = "timeline"
+       -> IssuesNumberLabels = "labels"
     @Issues
         ?> IssuesState = "state"
 
@@ -127,6 +209,7 @@ from!(
        -> PullsNumberRequestedReviewers = "requested_reviewers"
     @PullsNumber
        -> PullsNumberMerge = "merge"
+       -> PullsNumberReviews = "reviews"
 
     @GetQueryBuilder
        -> Repos = "repos"
@@ -168,6 +251,18 @@ from!(
     @Repo
        -> Tarball = "tarball"
        -> Zipball = "zipball"
+       -> Labels = "labels"
+       -> Milestones = "milestones"
+       -> Git = "git"
+       -> Releases = "releases"
+       -> Rules = "rules"
+       -> Rulesets = "rulesets"
+       -> Invitations = "invitations"
+       -> Hooks = "hooks"
+       -> Deployments = "deployments"
+       -> Environments = "environments"
+       -> CheckRuns = "check-runs"
+       -> CheckSuites = "check-suites"
     @Repos
        => Owner
 
@@ -175,6 +270,75 @@ from!(
        => ArchiveReference
     @Zipball
        => ArchiveReference
+    @Labels
+       => LabelsName
+    @Milestones
+       => MilestonesNumber
+    @MilestonesNumber
+       -> MilestonesNumberLabels = "labels"
+    @PullsNumberReviews
+       => PullsNumberReviewsId
+    @PullsNumberReviewsId
+       -> PullsNumberReviewsIdComments = "comments"
+    @Git
+       -> GitBlobs = "blobs"
+       -> GitCommits = "commits"
+       -> GitMatchingRefs = "matching-refs"
+       -> GitRef = "ref"
+       -> GitTags = "tags"
+       -> GitTrees = "trees"
+    @GitBlobs
+       => GitBlobsSha
+    @GitCommits
+       => GitCommitsSha
+    @GitTags
+       => GitTagsSha
+    @GitTrees
+       => GitTreesSha
+    @GitMatchingRefs
+       => GitMatchingRefsReference
+    @GitRef
+       => GitRefReference
+    @Releases
+       => ReleasesId
+       -> ReleasesAssets = "assets"
+       -> ReleasesLatest = "latest"
+       -> ReleasesTags = "tags"
+    @ReleasesAssets
+       => ReleasesAssetsId
+    @ReleasesId
+       -> ReleasesIdAssets = "assets"
+    @ReleasesTags
+       => ReleasesTagsTag
+    @Rules
+       -> RulesBranches = "branches"
+    @RulesBranches
+       => RulesBranchesBranch
+    @Rulesets
+       => RulesetsId
+    @Hooks
+       => HooksId
+    @HooksId
+       -> HooksIdConfig = "config"
+       -> HooksIdDeliveries = "deliveries"
+    @HooksIdDeliveries
+       => HooksIdDeliveriesId
+    @Deployments
+       => DeploymentsId
+    @DeploymentsId
+       -> DeploymentsIdStatuses = "statuses"
+    @DeploymentsIdStatuses
+       => DeploymentsIdStatusesId
+    @Environments
+       => EnvironmentsName
+    @CheckRuns
+       => CheckRunsId
+    @CheckRunsId
+       -> CheckRunsIdAnnotations = "annotations"
+    @CheckSuites
+       => CheckSuitesId
+    @CheckSuitesId
+       -> CheckSuitesIdCheckRuns = "check-runs"
 );
 
 impl_macro!(
@@ -191,9 +355,13 @@ impl_macro!(
         |=> restrictions -> BranchesNameProtectionRestrictions
         |=> required_pull_request_reviews -> BranchesNameProtectionRequiredPullRequestReviews
         |=> required_status_checks -> BranchesNameProtectionRequiredStatusChecks
+        |=> enforce_admins -> BranchesNameProtectionEnforceAdmins
+        |=> required_signatures -> BranchesNameProtectionRequiredSignatures
         |
     @BranchesNameProtectionRestrictions
         |=> users -> BranchesNameProtectionRestrictionsUsers
+        |=> teams -> BranchesNameProtectionRestrictionsTeams
+        |=> apps -> BranchesNameProtectionRestrictionsApps
         |
     @BranchesNameProtectionRequiredStatusChecks
         |=> contexts -> BranchesNameProtectionRequiredStatusChecksContexts
@@ -208,11 +376,15 @@ impl_macro!(
         |=> comments -> CommitsComments
         |=> status -> CommitsStatus
         |=> statuses -> CommitsStatuses
+        |=> check_runs -> CommitsCheckRuns
+        |=> check_suites -> CommitsCheckSuites
         |
     @CommitsReference
         |=> comments -> CommitsComments
         |=> status -> CommitsStatus
         |=> statuses -> CommitsStatuses
+        |=> check_runs -> CommitsCheckRuns
+        |=> check_suites -> CommitsCheckSuites
         |
     @Commits
         |
@@ -226,14 +398,21 @@ impl_macro!(
         |?> reference -> ContentsReference = ref_str
     @Issues
         |=> comments -> IssuesComments
+        |=> events -> IssuesEvents
         |
         |=> number -> IssuesNumber = issue_number
         |?> state -> IssuesState = state
     @IssuesComments
         |
         |=> id -> IssuesCommentsId = comment_id
+    @IssuesEvents
+        |
+        |=> id -> IssuesEventsId = event_id
     @IssuesNumber
         |=> comments -> IssuesNumberComments
+        |=> events -> IssuesNumberEvents
+        |=> timeline -> IssuesNumberTimeline
+        |=> labels -> IssuesNumberLabels
         |
     @Owner
         |
@@ -258,6 +437,18 @@ impl_macro!(
         |=> tags -> Tags
         |=> tarball -> Tarball
         |=> zipball -> Zipball
+        |=> labels -> Labels
+        |=> milestones -> Milestones
+        |=> git -> Git
+        |=> releases -> Releases
+        |=> rules -> Rules
+        |=> rulesets -> Rulesets
+        |=> invitations -> Invitations
+        |=> hooks -> Hooks
+        |=> deployments -> Deployments
+        |=> environments -> Environments
+        |=> check_runs -> CheckRuns
+        |=> check_suites -> CheckSuites
         |
     @Repos
         |
@@ -277,6 +468,7 @@ impl_macro!(
         |=> files -> PullsNumberFiles
         |=> requested_reviewers -> PullsNumberRequestedReviewers
         |=> merge -> PullsNumberMerge
+        |=> reviews -> PullsNumberReviews
         |
     @Tarball
         |
@@ -284,6 +476,182 @@ impl_macro!(
     @Zipball
         |
         |=> reference -> ArchiveReference = ref_str
+    @Labels
+        |
+        |/> name -> LabelsName = label_name
+    @Milestones
+        |
+        |=> number -> MilestonesNumber = milestone_number
+    @MilestonesNumber
+        |=> labels -> MilestonesNumberLabels
+        |
+    @PullsNumberReviews
+        |
+        |=> id -> PullsNumberReviewsId = review_id
+    @PullsNumberReviewsId
+        |=> comments -> PullsNumberReviewsIdComments
+        |
+    @Git
+        |=> blobs -> GitBlobs
+        |=> commits -> GitCommits
+        |=> matching_refs -> GitMatchingRefs
+        |=> git_ref -> GitRef
+        |=> tags -> GitTags
+        |=> trees -> GitTrees
+        |
+    @GitBlobs
+        |
+        |=> sha -> GitBlobsSha = sha_str
+    @GitCommits
+        |
+        |=> sha -> GitCommitsSha = sha_str
+    @GitTags
+        |
+        |=> sha -> GitTagsSha = sha_str
+    @GitTrees
+        |
+        |=> sha -> GitTreesSha = sha_str
+    @GitMatchingRefs
+        |
+        |=> reference -> GitMatchingRefsReference = ref_str
+    @GitRef
+        |
+        |=> reference -> GitRefReference = ref_str
+    @Releases
+        |=> assets -> ReleasesAssets
+        |=> latest -> ReleasesLatest
+        |=> tags -> ReleasesTags
+        |
+        |=> id -> ReleasesId = release_id
+    @ReleasesAssets
+        |
+        |=> id -> ReleasesAssetsId = asset_id
+    @ReleasesId
+        |=> assets -> ReleasesIdAssets
+        |
+    @ReleasesTags
+        |
+        |=> tag -> ReleasesTagsTag = tag_str
+    @Rules
+        |=> branches -> RulesBranches
+        |
+    @RulesBranches
+        |
+        |=> branch -> RulesBranchesBranch = branch_str
+    @Rulesets
+        |
+        |=> id -> RulesetsId = ruleset_id
+    @Hooks
+        |
+        |=> id -> HooksId = hook_id
+    @HooksId
+        |=> config -> HooksIdConfig
+        |=> deliveries -> HooksIdDeliveries
+        |
+    @HooksIdDeliveries
+        |
+        |=> id -> HooksIdDeliveriesId = delivery_id
+    @Deployments
+        |
+        |=> id -> DeploymentsId = deployment_id
+    @DeploymentsId
+        |=> statuses -> DeploymentsIdStatuses
+        |
+    @DeploymentsIdStatuses
+        |
+        |=> id -> DeploymentsIdStatusesId = status_id
+    @Environments
+        |
+        |/> name -> EnvironmentsName = environment_name
+    @CheckRuns
+        |
+        |=> id -> CheckRunsId = check_run_id
+    @CheckRunsId
+        |=> annotations -> CheckRunsIdAnnotations
+        |
+    @CheckSuites
+        |
+        |=> id -> CheckSuitesId = check_suite_id
+    @CheckSuitesId
+        |=> check_runs -> CheckSuitesIdCheckRuns
+        |
+);
+
+params!(
+    @IssuesComments
+        |?> sort = "sort"
+        |?> direction = "direction"
+        |?> since = "since"
+    @IssuesNumberComments
+        |?> since = "since"
+    @Milestones
+        |?> state = "state"
+        |?> sort = "sort"
+        |?> direction = "direction"
+        |?> per_page = "per_page"
+        |?> page = "page"
+    @GitTreesSha
+        |?> recursive = "recursive"
+    @Releases
+        |?> per_page = "per_page"
+        |?> page = "page"
+    @ReleasesIdAssets
+        |?> per_page = "per_page"
+        |?> page = "page"
+    @Rulesets
+        |?> includes_parents = "includes_parents"
+        |?> per_page = "per_page"
+        |?> page = "page"
+    @RulesBranchesBranch
+        |?> per_page = "per_page"
+        |?> page = "page"
+    @RulesetsId
+        |?> includes_parents = "includes_parents"
+    @Invitations
+        |?> per_page = "per_page"
+        |?> page = "page"
+    @Collaborators
+        |?> affiliation = "affiliation"
+        |?> permission = "permission"
+        |?> per_page = "per_page"
+        |?> page = "page"
+    @HooksIdDeliveries
+        |?> per_page = "per_page"
+        |?> cursor = "cursor"
+    @Deployments
+        |?> sha = "sha"
+        |?> reference = "ref"
+        |?> task = "task"
+        |?> environment = "environment"
+        |?> per_page = "per_page"
+        |?> page = "page"
+    @DeploymentsIdStatuses
+        |?> per_page = "per_page"
+        |?> page = "page"
+    @Environments
+        |?> per_page = "per_page"
+        |?> page = "page"
+    @CommitsCheckRuns
+        |?> check_name = "check_name"
+        |?> status = "status"
+        |?> filter = "filter"
+        |?> app_id = "app_id"
+        |?> per_page = "per_page"
+        |?> page = "page"
+    @CommitsCheckSuites
+        |?> app_id = "app_id"
+        |?> check_name = "check_name"
+        |?> per_page = "per_page"
+        |?> page = "page"
+    @CheckSuitesIdCheckRuns
+        |?> check_name = "check_name"
+        |?> status = "status"
+        |?> filter = "filter"
+        |?> per_page = "per_page"
+        |?> page = "page"
+    @CheckRunsIdAnnotations
+        |?> per_page = "per_page"
+        |?> page = "page"
 );
 
 exec!(ArchiveReference);
@@ -292,15 +660,25 @@ exec!(AssigneesUsername);
 exec!(Branches);
 exec!(BranchesName);
 exec!(BranchesNameProtection);
+exec!(BranchesNameProtectionEnforceAdmins);
 exec!(BranchesNameProtectionRestrictions);
+exec!(BranchesNameProtectionRestrictionsApps);
+exec!(BranchesNameProtectionRestrictionsTeams);
 exec!(BranchesNameProtectionRestrictionsUsers);
 exec!(BranchesNameProtectionRequiredPullRequestReviews);
+exec!(BranchesNameProtectionRequiredSignatures);
 exec!(BranchesNameProtectionRequiredStatusChecks);
 exec!(BranchesNameProtectionRequiredStatusChecksContexts);
+exec!(CheckRunsId);
+exec!(CheckRunsIdAnnotations);
+exec!(CheckSuitesId);
+exec!(CheckSuitesIdCheckRuns);
 exec!(Collaborators);
 exec!(CollaboratorsUsername);
 exec!(CollaboratorsUsernamePermission);
 exec!(Commits);
+exec!(CommitsCheckRuns);
+exec!(CommitsCheckSuites);
 exec!(CommitsSha);
 exec!(CommitsReference);
 exec!(CommitsComments);
@@ -309,15 +687,43 @@ exec!(CommitsStatuses);
 exec!(ContentsPath);
 exec!(ContentsReference);
 exec!(Contributors);
+exec!(Deployments);
+exec!(DeploymentsId);
+exec!(DeploymentsIdStatuses);
+exec!(DeploymentsIdStatusesId);
+exec!(Environments);
+exec!(EnvironmentsName);
 exec!(Events);
 exec!(Forks);
+exec!(GitBlobsSha);
+exec!(GitCommitsSha);
+exec!(GitMatchingRefsReference);
+exec!(GitRefReference);
+exec!(GitTagsSha);
+exec!(GitTreesSha);
+exec!(Hooks);
+exec!(HooksId);
+exec!(HooksIdConfig);
+exec!(HooksIdDeliveries);
+exec!(HooksIdDeliveriesId);
+exec!(Invitations);
 exec!(Issues);
 exec!(IssuesState);
 exec!(IssuesComments);
 exec!(IssuesCommentsId);
+exec!(IssuesEvents);
+exec!(IssuesEventsId);
 exec!(IssuesNumber);
 exec!(IssuesNumberComments);
+exec!(IssuesNumberEvents);
+exec!(IssuesNumberLabels);
+exec!(IssuesNumberTimeline);
+exec!(Labels);
+exec!(LabelsName);
 exec!(Languages);
+exec!(Milestones);
+exec!(MilestonesNumber);
+exec!(MilestonesNumberLabels);
 exec!(Notifications);
 exec!(Pulls);
 exec!(PullsComments);
@@ -328,8 +734,20 @@ exec!(PullsNumberCommits);
 exec!(PullsNumberFiles);
 exec!(PullsNumberRequestedReviewers);
 exec!(PullsNumberMerge);
+exec!(PullsNumberReviews);
+exec!(PullsNumberReviewsId);
+exec!(PullsNumberReviewsIdComments);
 exec!(Readme);
+exec!(Releases);
+exec!(ReleasesAssetsId);
+exec!(ReleasesId);
+exec!(ReleasesIdAssets);
+exec!(ReleasesLatest);
+exec!(ReleasesTagsTag);
 exec!(Repo);
+exec!(RulesBranchesBranch);
+exec!(Rulesets);
+exec!(RulesetsId);
 exec!(Stargazers);
 exec!(Subscribers);
 exec!(Subscription);
@@ -1,5 +1,29 @@
+mod checks;
+mod commit;
+mod contents;
 pub mod delete;
+mod deployments;
 pub mod get;
 pub mod patch;
 pub mod post;
 pub mod put;
+mod rulesets;
+pub mod upload;
+
+pub use self::checks::{
+    Annotation, AutoTriggerCheck, CheckRunAction, CheckRunFields, CheckRunList, CheckRunOutput,
+    CheckSuiteList, CheckSuitePreferences, CreateCheckRun, Image, UpdateCheckRun, MAX_ANNOTATIONS,
+};
+pub use self::commit::{CommitBuilder, CreateBlob, CreateCommit, CreateTree, FileMode, TreeEntry};
+pub use self::contents::{
+    decode_content, encode_content, CommitIdentity, DeleteFile, FileContent, UpdateFile,
+};
+pub use self::deployments::{
+    CreateDeployment, CreateDeploymentStatus, Deployment, DeploymentBranchPolicy, DeploymentStatus,
+    Environment, EnvironmentReviewer, ProtectionRule, UpdateEnvironment,
+};
+pub use self::rulesets::{
+    BranchRule, BypassActor, PatternParameters, PullRequestParameters, RefNameCondition,
+    RepositoryNameCondition, RequiredDeploymentsParameters, RequiredStatusChecksParameters, Rule,
+    Ruleset, RulesetConditions, StatusCheck, UpdateParameters,
+};
@@ -1 +1,201 @@
+//! Access the Repos portion of the GitHub API
+imports!();
+use crate::client::PatchQueryBuilder;
 
+new_type!(
+    Branches
+    BranchesName
+    BranchesNameProtection
+    BranchesNameProtectionRequiredPullRequestReviews
+    BranchesNameProtectionRequiredStatusChecks
+    CheckRuns
+    CheckRunsId
+    CheckSuites
+    CheckSuitesPreferences
+    Git
+    GitRefs
+    GitRefsReference
+    Hooks
+    HooksId
+    HooksIdConfig
+    Invitations
+    InvitationsId
+    Issues
+    IssuesComments
+    IssuesCommentsId
+    IssuesNumber
+    Labels
+    LabelsName
+    Milestones
+    MilestonesNumber
+    Owner
+    Pulls
+    PullsComments
+    PullsCommentsId
+    PullsNumber
+    Releases
+    ReleasesAssets
+    ReleasesAssetsId
+    ReleasesId
+    Repo
+    Repos
+);
+
+from!(
+    @PatchQueryBuilder
+        -> Repos = "repos"
+    @Repos
+        => Owner
+    @Owner
+        => Repo
+    @Repo
+        -> Issues = "issues"
+        -> Labels = "labels"
+        -> Milestones = "milestones"
+        -> Pulls = "pulls"
+        -> Git = "git"
+        -> Releases = "releases"
+        -> Branches = "branches"
+        -> Invitations = "invitations"
+        -> Hooks = "hooks"
+        -> CheckRuns = "check-runs"
+        -> CheckSuites = "check-suites"
+    @Issues
+        => IssuesNumber
+        -> IssuesComments = "comments"
+    @IssuesComments
+        => IssuesCommentsId
+    @Labels
+        => LabelsName
+    @Milestones
+        => MilestonesNumber
+    @Pulls
+        => PullsNumber
+        -> PullsComments = "comments"
+    @PullsComments
+        => PullsCommentsId
+    @Git
+        -> GitRefs = "refs"
+    @GitRefs
+        => GitRefsReference
+    @Releases
+        => ReleasesId
+        -> ReleasesAssets = "assets"
+    @ReleasesAssets
+        => ReleasesAssetsId
+    @Branches
+        => BranchesName
+    @BranchesName
+        -> BranchesNameProtection = "protection"
+    @BranchesNameProtection
+        -> BranchesNameProtectionRequiredStatusChecks = "required_status_checks"
+        -> BranchesNameProtectionRequiredPullRequestReviews = "required_pull_request_reviews"
+    @Invitations
+        => InvitationsId
+    @Hooks
+        => HooksId
+    @HooksId
+        -> HooksIdConfig = "config"
+    @CheckRuns
+        => CheckRunsId
+    @CheckSuites
+        -> CheckSuitesPreferences = "preferences"
+);
+
+impl_macro!(
+    @Repos
+        |
+        |=> owner -> Owner = username_str
+    @Owner
+        |
+        |=> repo -> Repo = repo_str
+    @Repo
+        |=> issues -> Issues
+        |=> labels -> Labels
+        |=> milestones -> Milestones
+        |=> pulls -> Pulls
+        |=> git -> Git
+        |=> releases -> Releases
+        |=> branches -> Branches
+        |=> invitations -> Invitations
+        |=> hooks -> Hooks
+        |=> check_runs -> CheckRuns
+        |=> check_suites -> CheckSuites
+        |
+    @Issues
+        |=> comments -> IssuesComments
+        |
+        |=> number -> IssuesNumber = issue_number
+    @IssuesComments
+        |
+        |=> id -> IssuesCommentsId = comment_id
+    @Labels
+        |
+        |/> name -> LabelsName = label_name
+    @Milestones
+        |
+        |=> number -> MilestonesNumber = milestone_number
+    @Pulls
+        |=> comments -> PullsComments
+        |
+        |=> number -> PullsNumber = number_str
+    @PullsComments
+        |
+        |=> id -> PullsCommentsId = id_str
+    @Git
+        |=> refs -> GitRefs
+        |
+    @GitRefs
+        |
+        |=> reference -> GitRefsReference = ref_str
+    @Releases
+        |=> assets -> ReleasesAssets
+        |
+        |=> id -> ReleasesId = release_id
+    @ReleasesAssets
+        |
+        |=> id -> ReleasesAssetsId = asset_id
+    @Branches
+        |
+        |=> name -> BranchesName = name
+    @BranchesName
+        |=> protection -> BranchesNameProtection
+        |
+    @BranchesNameProtection
+        |=> required_status_checks -> BranchesNameProtectionRequiredStatusChecks
+        |=> required_pull_request_reviews -> BranchesNameProtectionRequiredPullRequestReviews
+        |
+    @Invitations
+        |
+        |=> id -> InvitationsId = invitation_id
+    @Hooks
+        |
+        |=> id -> HooksId = hook_id
+    @HooksId
+        |=> config -> HooksIdConfig
+        |
+    @CheckRuns
+        |
+        |=> id -> CheckRunsId = check_run_id
+    @CheckSuites
+        |=> preferences -> CheckSuitesPreferences
+        |
+);
+
+exec!(BranchesNameProtectionRequiredPullRequestReviews);
+exec!(BranchesNameProtectionRequiredStatusChecks);
+exec!(CheckRunsId);
+exec!(CheckSuitesPreferences);
+exec!(GitRefsReference);
+exec!(HooksId);
+exec!(HooksIdConfig);
+exec!(InvitationsId);
+exec!(IssuesCommentsId);
+exec!(IssuesNumber);
+exec!(LabelsName);
+exec!(MilestonesNumber);
+exec!(PullsCommentsId);
+exec!(PullsNumber);
+exec!(ReleasesAssetsId);
+exec!(ReleasesId);
+exec!(Repo);
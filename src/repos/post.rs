@@ -3,12 +3,65 @@ imports!();
 use crate::client::PostQueryBuilder;
 
 new_type!(
+    Branches
+    BranchesName
+    BranchesNameProtection
+    BranchesNameProtectionEnforceAdmins
+    BranchesNameProtectionRequiredSignatures
+    BranchesNameProtectionRequiredStatusChecks
+    BranchesNameProtectionRequiredStatusChecksContexts
+    BranchesNameProtectionRestrictions
+    BranchesNameProtectionRestrictionsApps
+    BranchesNameProtectionRestrictionsTeams
+    BranchesNameProtectionRestrictionsUsers
+    CheckRuns
+    CheckRunsId
+    CheckRunsIdRerequest
+    CheckSuites
+    CheckSuitesId
+    CheckSuitesIdRerequest
+    Deployments
+    DeploymentsId
+    DeploymentsIdStatuses
+    Generate
+    Git
+    GitBlobs
+    GitCommits
+    GitRefs
+    GitTags
+    GitTrees
+    Hooks
+    HooksId
+    HooksIdDeliveries
+    HooksIdDeliveriesId
+    HooksIdDeliveriesIdAttempts
+    HooksIdPings
+    HooksIdTests
     Sha
     Statuses
     Repo
     Repos
     Owner
     Issues
+    IssuesNumber
+    IssuesNumberAssignees
+    IssuesNumberComments
+    IssuesNumberLabels
+    Labels
+    Milestones
+    Pulls
+    PullsNumber
+    PullsNumberComments
+    PullsNumberCommentsId
+    PullsNumberCommentsIdReplies
+    PullsNumberRequestedReviewers
+    PullsNumberReviews
+    PullsNumberReviewsId
+    PullsNumberReviewsIdEvents
+    Releases
+    ReleasesGenerateNotes
+    Rulesets
+    Transfer
 );
 
 from!(
@@ -21,9 +74,86 @@ from!(
     @Repo
         -> Statuses = "statuses"
         -> Issues = "issues"
+        -> Labels = "labels"
+        -> Milestones = "milestones"
+        -> Pulls = "pulls"
+        -> Generate = "generate"
+        -> Transfer = "transfer"
+        -> Git = "git"
+        -> Releases = "releases"
+        -> Branches = "branches"
+        -> Rulesets = "rulesets"
+        -> Hooks = "hooks"
+        -> Deployments = "deployments"
+        -> CheckRuns = "check-runs"
+        -> CheckSuites = "check-suites"
     @Statuses
         => Sha
-
+    @Issues
+        => IssuesNumber
+    @IssuesNumber
+        -> IssuesNumberComments = "comments"
+        -> IssuesNumberLabels = "labels"
+        -> IssuesNumberAssignees = "assignees"
+    @Pulls
+        => PullsNumber
+    @PullsNumber
+        -> PullsNumberReviews = "reviews"
+        -> PullsNumberRequestedReviewers = "requested_reviewers"
+        -> PullsNumberComments = "comments"
+    @PullsNumberReviews
+        => PullsNumberReviewsId
+    @PullsNumberReviewsId
+        -> PullsNumberReviewsIdEvents = "events"
+    @PullsNumberComments
+        => PullsNumberCommentsId
+    @PullsNumberCommentsId
+        -> PullsNumberCommentsIdReplies = "replies"
+    @Git
+        -> GitBlobs = "blobs"
+        -> GitCommits = "commits"
+        -> GitRefs = "refs"
+        -> GitTags = "tags"
+        -> GitTrees = "trees"
+    @Releases
+        -> ReleasesGenerateNotes = "generate-notes"
+    @Branches
+        => BranchesName
+    @BranchesName
+        -> BranchesNameProtection = "protection"
+    @BranchesNameProtection
+        -> BranchesNameProtectionEnforceAdmins = "enforce_admins"
+        -> BranchesNameProtectionRequiredSignatures = "required_signatures"
+        -> BranchesNameProtectionRequiredStatusChecks = "required_status_checks"
+        -> BranchesNameProtectionRestrictions = "restrictions"
+    @BranchesNameProtectionRequiredStatusChecks
+        -> BranchesNameProtectionRequiredStatusChecksContexts = "contexts"
+    @BranchesNameProtectionRestrictions
+        -> BranchesNameProtectionRestrictionsUsers = "users"
+        -> BranchesNameProtectionRestrictionsTeams = "teams"
+        -> BranchesNameProtectionRestrictionsApps = "apps"
+    @Hooks
+        => HooksId
+    @HooksId
+        -> HooksIdPings = "pings"
+        -> HooksIdTests = "tests"
+        -> HooksIdDeliveries = "deliveries"
+    @HooksIdDeliveries
+        => HooksIdDeliveriesId
+    @HooksIdDeliveriesId
+        -> HooksIdDeliveriesIdAttempts = "attempts"
+    @Deployments
+        => DeploymentsId
+    @DeploymentsId
+        -> DeploymentsIdStatuses = "statuses"
+    @CheckRuns
+        => CheckRunsId
+    @CheckRunsId
+        -> CheckRunsIdRerequest = "rerequest"
+    @CheckSuites
+        => CheckSuitesId
+    @CheckSuitesId
+        -> CheckSuitesIdRerequest = "rerequest"
 );
 
 impl_macro!(
@@ -36,13 +166,151 @@ impl_macro!(
     @Repo
         |=> statuses -> Statuses
         |=> issues -> Issues
+        |=> labels -> Labels
+        |=> milestones -> Milestones
+        |=> pulls -> Pulls
+        |=> generate -> Generate
+        |=> transfer -> Transfer
+        |=> git -> Git
+        |=> releases -> Releases
+        |=> branches -> Branches
+        |=> rulesets -> Rulesets
+        |=> hooks -> Hooks
+        |=> deployments -> Deployments
+        |=> check_runs -> CheckRuns
+        |=> check_suites -> CheckSuites
         |
     @Statuses
         |
         |=> sha -> Sha = sha_str
     @Issues
         |
+        |=> number -> IssuesNumber = issue_number
+    @IssuesNumber
+        |=> comments -> IssuesNumberComments
+        |=> labels -> IssuesNumberLabels
+        |=> assignees -> IssuesNumberAssignees
+        |
+    @Pulls
+        |
+        |=> number -> PullsNumber = number_str
+    @PullsNumber
+        |=> reviews -> PullsNumberReviews
+        |=> requested_reviewers -> PullsNumberRequestedReviewers
+        |=> comments -> PullsNumberComments
+        |
+    @PullsNumberReviews
+        |
+        |=> id -> PullsNumberReviewsId = review_id
+    @PullsNumberReviewsId
+        |=> events -> PullsNumberReviewsIdEvents
+        |
+    @PullsNumberComments
+        |
+        |=> id -> PullsNumberCommentsId = comment_id
+    @PullsNumberCommentsId
+        |=> replies -> PullsNumberCommentsIdReplies
+        |
+    @Git
+        |=> blobs -> GitBlobs
+        |=> commits -> GitCommits
+        |=> refs -> GitRefs
+        |=> tags -> GitTags
+        |=> trees -> GitTrees
+        |
+    @Releases
+        |=> generate_notes -> ReleasesGenerateNotes
+        |
+    @Branches
+        |
+        |=> name -> BranchesName = name
+    @BranchesName
+        |=> protection -> BranchesNameProtection
+        |
+    @BranchesNameProtection
+        |=> enforce_admins -> BranchesNameProtectionEnforceAdmins
+        |=> required_signatures -> BranchesNameProtectionRequiredSignatures
+        |=> required_status_checks -> BranchesNameProtectionRequiredStatusChecks
+        |=> restrictions -> BranchesNameProtectionRestrictions
+        |
+    @BranchesNameProtectionRequiredStatusChecks
+        |=> contexts -> BranchesNameProtectionRequiredStatusChecksContexts
+        |
+    @BranchesNameProtectionRestrictions
+        |=> users -> BranchesNameProtectionRestrictionsUsers
+        |=> teams -> BranchesNameProtectionRestrictionsTeams
+        |=> apps -> BranchesNameProtectionRestrictionsApps
+        |
+    @Hooks
+        |
+        |=> id -> HooksId = hook_id
+    @HooksId
+        |=> pings -> HooksIdPings
+        |=> tests -> HooksIdTests
+        |=> deliveries -> HooksIdDeliveries
+        |
+    @HooksIdDeliveries
+        |
+        |=> id -> HooksIdDeliveriesId = delivery_id
+    @HooksIdDeliveriesId
+        |=> attempts -> HooksIdDeliveriesIdAttempts
+        |
+    @Deployments
+        |
+        |=> id -> DeploymentsId = deployment_id
+    @DeploymentsId
+        |=> statuses -> DeploymentsIdStatuses
+        |
+    @CheckRuns
+        |
+        |=> id -> CheckRunsId = check_run_id
+    @CheckRunsId
+        |=> rerequest -> CheckRunsIdRerequest
+        |
+    @CheckSuites
+        |
+        |=> id -> CheckSuitesId = check_suite_id
+    @CheckSuitesId
+        |=> rerequest -> CheckSuitesIdRerequest
+        |
 );
 
+exec!(BranchesNameProtectionEnforceAdmins);
+exec!(BranchesNameProtectionRequiredSignatures);
+exec!(BranchesNameProtectionRequiredStatusChecksContexts);
+exec!(BranchesNameProtectionRestrictionsApps);
+exec!(BranchesNameProtectionRestrictionsTeams);
+exec!(BranchesNameProtectionRestrictionsUsers);
+exec!(CheckRuns);
+exec!(CheckRunsIdRerequest);
+exec!(CheckSuites);
+exec!(CheckSuitesIdRerequest);
+exec!(Deployments);
+exec!(DeploymentsIdStatuses);
+exec!(Generate);
+exec!(GitBlobs);
+exec!(GitCommits);
+exec!(GitRefs);
+exec!(GitTags);
+exec!(GitTrees);
+exec!(Hooks);
+exec!(HooksIdDeliveriesIdAttempts);
+exec!(HooksIdPings);
+exec!(HooksIdTests);
 exec!(Sha);
 exec!(Issues);
+exec!(IssuesNumberAssignees);
+exec!(IssuesNumberComments);
+exec!(IssuesNumberLabels);
+exec!(Labels);
+exec!(Milestones);
+exec!(Pulls);
+exec!(PullsNumberComments);
+exec!(PullsNumberCommentsIdReplies);
+exec!(PullsNumberRequestedReviewers);
+exec!(PullsNumberReviews);
+exec!(PullsNumberReviewsIdEvents);
+exec!(Releases);
+exec!(ReleasesGenerateNotes);
+exec!(Rulesets);
+exec!(Transfer);
@@ -1 +1,175 @@
+//! Access the Repos portion of the GitHub API
+imports!();
+use crate::client::PutQueryBuilder;
 
+new_type!(
+    Branches
+    BranchesName
+    BranchesNameProtection
+    BranchesNameProtectionRequiredStatusChecks
+    BranchesNameProtectionRequiredStatusChecksContexts
+    BranchesNameProtectionRestrictions
+    BranchesNameProtectionRestrictionsApps
+    BranchesNameProtectionRestrictionsTeams
+    BranchesNameProtectionRestrictionsUsers
+    Collaborators
+    CollaboratorsUsername
+    Contents
+    ContentsPath
+    Environments
+    EnvironmentsName
+    Issues
+    IssuesNumber
+    IssuesNumberLabels
+    IssuesNumberLock
+    Owner
+    Pulls
+    PullsNumber
+    PullsNumberMerge
+    PullsNumberReviews
+    PullsNumberReviewsId
+    PullsNumberReviewsIdDismissals
+    PullsNumberUpdateBranch
+    Repo
+    Repos
+    Rulesets
+    RulesetsId
+);
+
+from!(
+    @PutQueryBuilder
+        -> Repos = "repos"
+    @Repos
+        => Owner
+    @Owner
+        => Repo
+    @Repo
+        -> Issues = "issues"
+        -> Pulls = "pulls"
+        -> Contents = "contents"
+        -> Branches = "branches"
+        -> Rulesets = "rulesets"
+        -> Collaborators = "collaborators"
+        -> Environments = "environments"
+    @Issues
+        => IssuesNumber
+    @IssuesNumber
+        -> IssuesNumberLock = "lock"
+        -> IssuesNumberLabels = "labels"
+    @Pulls
+        => PullsNumber
+    @PullsNumber
+        -> PullsNumberMerge = "merge"
+        -> PullsNumberUpdateBranch = "update-branch"
+        -> PullsNumberReviews = "reviews"
+    @PullsNumberReviews
+        => PullsNumberReviewsId
+    @PullsNumberReviewsId
+        -> PullsNumberReviewsIdDismissals = "dismissals"
+    @Contents
+        => ContentsPath
+    @Branches
+        => BranchesName
+    @BranchesName
+        -> BranchesNameProtection = "protection"
+    @BranchesNameProtection
+        -> BranchesNameProtectionRequiredStatusChecks = "required_status_checks"
+        -> BranchesNameProtectionRestrictions = "restrictions"
+    @BranchesNameProtectionRequiredStatusChecks
+        -> BranchesNameProtectionRequiredStatusChecksContexts = "contexts"
+    @BranchesNameProtectionRestrictions
+        -> BranchesNameProtectionRestrictionsUsers = "users"
+        -> BranchesNameProtectionRestrictionsTeams = "teams"
+        -> BranchesNameProtectionRestrictionsApps = "apps"
+    @Rulesets
+        => RulesetsId
+    @Collaborators
+        => CollaboratorsUsername
+    @Environments
+        => EnvironmentsName
+);
+
+impl_macro!(
+    @Repos
+        |
+        |=> owner -> Owner = username_str
+    @Owner
+        |
+        |=> repo -> Repo = repo_str
+    @Repo
+        |=> issues -> Issues
+        |=> pulls -> Pulls
+        |=> contents -> Contents
+        |=> branches -> Branches
+        |=> rulesets -> Rulesets
+        |=> collaborators -> Collaborators
+        |=> environments -> Environments
+        |
+    @Issues
+        |
+        |=> number -> IssuesNumber = issue_number
+    @IssuesNumber
+        |=> lock -> IssuesNumberLock
+        |=> labels -> IssuesNumberLabels
+        |
+    @Pulls
+        |
+        |=> number -> PullsNumber = number_str
+    @PullsNumber
+        |=> merge -> PullsNumberMerge
+        |=> update_branch -> PullsNumberUpdateBranch
+        |=> reviews -> PullsNumberReviews
+        |
+    @PullsNumberReviews
+        |
+        |=> id -> PullsNumberReviewsId = review_id
+    @PullsNumberReviewsId
+        |=> dismissals -> PullsNumberReviewsIdDismissals
+        |
+    @Contents
+        |
+        |=> path -> ContentsPath = path_str
+    @Branches
+        |
+        |=> name -> BranchesName = name
+    @BranchesName
+        |=> protection -> BranchesNameProtection
+        |
+    @BranchesNameProtection
+        |=> required_status_checks -> BranchesNameProtectionRequiredStatusChecks
+        |=> restrictions -> BranchesNameProtectionRestrictions
+        |
+    @BranchesNameProtectionRequiredStatusChecks
+        |=> contexts -> BranchesNameProtectionRequiredStatusChecksContexts
+        |
+    @BranchesNameProtectionRestrictions
+        |=> users -> BranchesNameProtectionRestrictionsUsers
+        |=> teams -> BranchesNameProtectionRestrictionsTeams
+        |=> apps -> BranchesNameProtectionRestrictionsApps
+        |
+    @Rulesets
+        |
+        |=> id -> RulesetsId = ruleset_id
+    @Collaborators
+        |
+        |=> username -> CollaboratorsUsername = username
+    @Environments
+        |
+        |/> name -> EnvironmentsName = environment_name
+);
+
+exec!(BranchesNameProtection);
+exec!(BranchesNameProtectionRequiredStatusChecksContexts);
+exec!(BranchesNameProtectionRestrictionsApps);
+exec!(BranchesNameProtectionRestrictionsTeams);
+exec!(BranchesNameProtectionRestrictionsUsers);
+exec!(CollaboratorsUsername);
+exec!(ContentsPath);
+exec!(EnvironmentsName);
+exec!(IssuesNumberLabels);
+exec!(IssuesNumberLock);
+exec!(PullsNumberMerge);
+exec!(PullsNumberReviewsId);
+exec!(PullsNumberReviewsIdDismissals);
+exec!(PullsNumberUpdateBranch);
+exec!(RulesetsId);
@@ -0,0 +1,320 @@
+//! Typed rulesets for the repository and organization rulesets endpoints
+//!
+//! The same `Ruleset` is sent to create or update a ruleset and comes back
+//! from reading one:
+//!
+//! ```text
+//! let ruleset = Ruleset::new("main", "active")
+//!     .target("branch")
+//!     .include_refs(&["~DEFAULT_BRANCH"])
+//!     .rule(Rule::Deletion)
+//!     .rule(Rule::NonFastForward)
+//!     .rule(Rule::PullRequest(PullRequestParameters {
+//!         required_approving_review_count: 1,
+//!         ..Default::default()
+//!     }));
+//! g.post(ruleset).repos().owner("o").repo("r").rulesets().execute::<Value>()?;
+//! ```
+use serde::Serialize;
+use serde_json::{self, Map, Value};
+use std::convert::TryFrom;
+
+/// A ruleset for a repository or an organization
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct Ruleset {
+    /// Only set on rulesets returned by GitHub
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<u64>,
+    pub name: String,
+    /// `branch`, `tag` or `push`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub target: Option<String>,
+    /// `disabled`, `active` or `evaluate`
+    pub enforcement: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub source_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub source: Option<String>,
+    /// GitHub leaves fields that aren't sent unchanged on update, so `None`
+    /// keeps the current bypass actors and an empty list removes them all
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bypass_actors: Option<Vec<BypassActor>>,
+    /// `None` keeps the current conditions on update, see `clear_conditions`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub conditions: Option<RulesetConditions>,
+    /// `None` keeps the current rules on update and an empty list removes
+    /// them all
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rules: Option<Vec<Rule>>,
+}
+
+impl Ruleset {
+    pub fn new(name: &str, enforcement: &str) -> Self {
+        Ruleset {
+            name: name.to_string(),
+            enforcement: enforcement.to_string(),
+            ..Default::default()
+        }
+    }
+
+    pub fn target(mut self, target: &str) -> Self {
+        self.target = Some(target.to_string());
+        self
+    }
+
+    /// Apply the ruleset to refs matching these patterns, e.g.
+    /// `refs/heads/main`, `refs/heads/release/*` or `~DEFAULT_BRANCH`
+    pub fn include_refs(mut self, refs: &[&str]) -> Self {
+        let ref_name = self.ref_name();
+        ref_name.include.extend(refs.iter().map(|r| r.to_string()));
+        self
+    }
+
+    /// Leave refs matching these patterns out of the ruleset
+    pub fn exclude_refs(mut self, refs: &[&str]) -> Self {
+        let ref_name = self.ref_name();
+        ref_name.exclude.extend(refs.iter().map(|r| r.to_string()));
+        self
+    }
+
+    pub fn bypass_actor(mut self, actor: BypassActor) -> Self {
+        self.bypass_actors.get_or_insert_with(Vec::new).push(actor);
+        self
+    }
+
+    /// Remove every bypass actor when the ruleset is written
+    pub fn clear_bypass_actors(mut self) -> Self {
+        self.bypass_actors = Some(Vec::new());
+        self
+    }
+
+    /// Remove every condition when the ruleset is written. Refs or
+    /// repositories added afterwards are sent instead.
+    pub fn clear_conditions(mut self) -> Self {
+        self.conditions = Some(RulesetConditions::default());
+        self
+    }
+
+    pub fn rule(mut self, rule: Rule) -> Self {
+        self.rules.get_or_insert_with(Vec::new).push(rule);
+        self
+    }
+
+    /// Remove every rule when the ruleset is written. Rules added afterwards
+    /// are sent instead.
+    pub fn clear_rules(mut self) -> Self {
+        self.rules = Some(Vec::new());
+        self
+    }
+
+    fn ref_name(&mut self) -> &mut RefNameCondition {
+        self.conditions
+            .get_or_insert_with(Default::default)
+            .ref_name
+            .get_or_insert_with(Default::default)
+    }
+}
+
+/// Someone allowed to bypass a ruleset
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct BypassActor {
+    pub actor_id: Option<u64>,
+    /// `Integration`, `OrganizationAdmin`, `RepositoryRole`, `Team` or
+    /// `DeployKey`
+    pub actor_type: String,
+    /// `always` or `pull_request`
+    pub bypass_mode: String,
+}
+
+/// What a ruleset applies to
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct RulesetConditions {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ref_name: Option<RefNameCondition>,
+    /// Only used by organization rulesets
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub repository_name: Option<RepositoryNameCondition>,
+    /// Conditions this library doesn't model, e.g. `repository_id` or
+    /// `repository_property`, kept so they survive being read and written
+    /// back
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct RefNameCondition {
+    #[serde(default)]
+    pub include: Vec<String>,
+    #[serde(default)]
+    pub exclude: Vec<String>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct RepositoryNameCondition {
+    #[serde(default)]
+    pub include: Vec<String>,
+    #[serde(default)]
+    pub exclude: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub protected: Option<bool>,
+}
+
+/// A single rule in a ruleset. Rule types this library doesn't know about
+/// are kept as `Unknown` so they survive being read and written back.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(try_from = "RawRule", into = "RawRule")]
+pub enum Rule {
+    Creation,
+    Update(UpdateParameters),
+    Deletion,
+    RequiredLinearHistory,
+    RequiredSignatures,
+    NonFastForward,
+    RequiredDeployments(RequiredDeploymentsParameters),
+    PullRequest(PullRequestParameters),
+    RequiredStatusChecks(RequiredStatusChecksParameters),
+    CommitMessagePattern(PatternParameters),
+    CommitAuthorEmailPattern(PatternParameters),
+    CommitterEmailPattern(PatternParameters),
+    BranchNamePattern(PatternParameters),
+    TagNamePattern(PatternParameters),
+    Unknown { kind: String, parameters: Value },
+}
+
+/// How a rule looks on the wire
+#[derive(Serialize, Deserialize)]
+struct RawRule {
+    #[serde(rename = "type")]
+    kind: String,
+    #[serde(default, skip_serializing_if = "Value::is_null")]
+    parameters: Value,
+}
+
+impl TryFrom<RawRule> for Rule {
+    type Error = serde_json::Error;
+
+    fn try_from(raw: RawRule) -> Result<Self, Self::Error> {
+        let p = raw.parameters;
+        Ok(match raw.kind.as_str() {
+            "creation" => Rule::Creation,
+            "update" if p.is_null() => Rule::Update(UpdateParameters::default()),
+            "update" => Rule::Update(serde_json::from_value(p)?),
+            "deletion" => Rule::Deletion,
+            "required_linear_history" => Rule::RequiredLinearHistory,
+            "required_signatures" => Rule::RequiredSignatures,
+            "non_fast_forward" => Rule::NonFastForward,
+            "required_deployments" => Rule::RequiredDeployments(serde_json::from_value(p)?),
+            "pull_request" => Rule::PullRequest(serde_json::from_value(p)?),
+            "required_status_checks" => Rule::RequiredStatusChecks(serde_json::from_value(p)?),
+            "commit_message_pattern" => Rule::CommitMessagePattern(serde_json::from_value(p)?),
+            "commit_author_email_pattern" => {
+                Rule::CommitAuthorEmailPattern(serde_json::from_value(p)?)
+            }
+            "committer_email_pattern" => Rule::CommitterEmailPattern(serde_json::from_value(p)?),
+            "branch_name_pattern" => Rule::BranchNamePattern(serde_json::from_value(p)?),
+            "tag_name_pattern" => Rule::TagNamePattern(serde_json::from_value(p)?),
+            _ => Rule::Unknown {
+                kind: raw.kind,
+                parameters: p,
+            },
+        })
+    }
+}
+
+impl From<Rule> for RawRule {
+    fn from(rule: Rule) -> Self {
+        // None of the parameter types can fail to serialize
+        fn params<T: Serialize>(p: T) -> Value {
+            serde_json::to_value(p).unwrap_or_default()
+        }
+        let (kind, parameters) = match rule {
+            Rule::Creation => ("creation", Value::Null),
+            Rule::Update(p) => ("update", params(p)),
+            Rule::Deletion => ("deletion", Value::Null),
+            Rule::RequiredLinearHistory => ("required_linear_history", Value::Null),
+            Rule::RequiredSignatures => ("required_signatures", Value::Null),
+            Rule::NonFastForward => ("non_fast_forward", Value::Null),
+            Rule::RequiredDeployments(p) => ("required_deployments", params(p)),
+            Rule::PullRequest(p) => ("pull_request", params(p)),
+            Rule::RequiredStatusChecks(p) => ("required_status_checks", params(p)),
+            Rule::CommitMessagePattern(p) => ("commit_message_pattern", params(p)),
+            Rule::CommitAuthorEmailPattern(p) => ("commit_author_email_pattern", params(p)),
+            Rule::CommitterEmailPattern(p) => ("committer_email_pattern", params(p)),
+            Rule::BranchNamePattern(p) => ("branch_name_pattern", params(p)),
+            Rule::TagNamePattern(p) => ("tag_name_pattern", params(p)),
+            Rule::Unknown { kind, parameters } => return RawRule { kind, parameters },
+        };
+        RawRule {
+            kind: kind.to_string(),
+            parameters,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct UpdateParameters {
+    #[serde(default)]
+    pub update_allows_fetch_and_merge: bool,
+    /// Parameters this library doesn't model, kept so they survive being
+    /// read and written back
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct RequiredDeploymentsParameters {
+    pub required_deployment_environments: Vec<String>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct PullRequestParameters {
+    pub dismiss_stale_reviews_on_push: bool,
+    pub require_code_owner_review: bool,
+    pub require_last_push_approval: bool,
+    pub required_approving_review_count: u32,
+    pub required_review_thread_resolution: bool,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct RequiredStatusChecksParameters {
+    pub required_status_checks: Vec<StatusCheck>,
+    pub strict_required_status_checks_policy: bool,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct StatusCheck {
+    pub context: String,
+    /// Only accept the check from this GitHub App
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub integration_id: Option<u64>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct PatternParameters {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(default)]
+    pub negate: bool,
+    /// `starts_with`, `ends_with`, `contains` or `regex`
+    pub operator: String,
+    pub pattern: String,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+/// A rule returned by `GET /repos/:owner/:repo/rules/branches/:branch`, along
+/// with the ruleset it comes from
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+pub struct BranchRule {
+    #[serde(flatten)]
+    pub rule: Rule,
+    pub ruleset_source_type: String,
+    pub ruleset_source: String,
+    pub ruleset_id: u64,
+}
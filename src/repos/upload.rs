@@ -0,0 +1,53 @@
+//! Access the Repos portion of the GitHub API
+imports!();
+use crate::client::UploadQueryBuilder;
+
+new_type!(
+    Owner
+    Releases
+    ReleasesId
+    ReleasesIdAssets
+    Repo
+    Repos
+);
+
+from!(
+    @UploadQueryBuilder
+        -> Repos = "repos"
+    @Repos
+        => Owner
+    @Owner
+        => Repo
+    @Repo
+        -> Releases = "releases"
+    @Releases
+        => ReleasesId
+    @ReleasesId
+        -> ReleasesIdAssets = "assets"
+);
+
+impl_macro!(
+    @Repos
+        |
+        |=> owner -> Owner = username_str
+    @Owner
+        |
+        |=> repo -> Repo = repo_str
+    @Repo
+        |=> releases -> Releases
+        |
+    @Releases
+        |
+        |=> id -> ReleasesId = release_id
+    @ReleasesId
+        |=> assets -> ReleasesIdAssets
+        |
+);
+
+params!(
+    @ReleasesIdAssets
+        |?> name = "name"
+        |?> label = "label"
+);
+
+exec!(ReleasesIdAssets);
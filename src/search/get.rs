@@ -1 +1,139 @@
+//! Access the Search portion of the GitHub API
+//!
+//! Search has its own, much stricter, rate limit. Use
+//! `headers::rate_limit_resource` on the response headers to check that the
+//! limits you're reading are for the `search` bucket. GitHub may also give up
+//! on a query that takes too long, in which case `incomplete_results` is set
+//! on the `SearchResults` that come back. The `q` parameter can either be
+//! given as is or built up with a `SearchQuery`.
+imports!();
+use crate::client::GetQueryBuilder;
+use crate::search::SearchQuery;
+use hyper::header::{HeaderValue, ACCEPT};
 
+new_type!(
+    Search
+    SearchCode
+    SearchCommits
+    SearchIssues
+    SearchLabels
+    SearchRepositories
+    SearchTopics
+    SearchUsers
+);
+
+from!(
+    @GetQueryBuilder
+        -> Search = "search"
+    @Search
+        -> SearchCode = "code"
+        -> SearchCommits = "commits"
+        -> SearchIssues = "issues"
+        -> SearchLabels = "labels"
+        -> SearchRepositories = "repositories"
+        -> SearchTopics = "topics"
+        -> SearchUsers = "users"
+);
+
+impl_macro!(
+    @Search
+        |=> code -> SearchCode
+        |=> commits -> SearchCommits
+        |=> issues -> SearchIssues
+        |=> labels -> SearchLabels
+        |=> repositories -> SearchRepositories
+        |=> topics -> SearchTopics
+        |=> users -> SearchUsers
+        |
+);
+
+params!(
+    @SearchCode
+        |?> q = "q"
+        |?> sort = "sort"
+        |?> order = "order"
+        |?> per_page = "per_page"
+        |?> page = "page"
+    @SearchCommits
+        |?> q = "q"
+        |?> sort = "sort"
+        |?> order = "order"
+        |?> per_page = "per_page"
+        |?> page = "page"
+    @SearchIssues
+        |?> q = "q"
+        |?> sort = "sort"
+        |?> order = "order"
+        |?> per_page = "per_page"
+        |?> page = "page"
+    @SearchLabels
+        |?> repository_id = "repository_id"
+        |?> q = "q"
+        |?> sort = "sort"
+        |?> order = "order"
+        |?> per_page = "per_page"
+        |?> page = "page"
+    @SearchRepositories
+        |?> q = "q"
+        |?> sort = "sort"
+        |?> order = "order"
+        |?> per_page = "per_page"
+        |?> page = "page"
+    @SearchTopics
+        |?> q = "q"
+        |?> per_page = "per_page"
+        |?> page = "page"
+    @SearchUsers
+        |?> q = "q"
+        |?> sort = "sort"
+        |?> order = "order"
+        |?> per_page = "per_page"
+        |?> page = "page"
+);
+
+/// Generate the functions shared by every search endpoint. `query` sets the
+/// `q` parameter from a `SearchQuery` and `text_match` asks GitHub to include
+/// `text_matches` metadata, showing where the query matched, with each item
+macro_rules! search {
+    ($($i: ident)*) => (
+        $(
+        impl<'g> $i<'g> {
+            /// Set the `q` parameter using a `SearchQuery`
+            pub fn query(self, query: &SearchQuery) -> Self {
+                self.q(&query.to_string())
+            }
+
+            /// Request text match metadata for each result using the
+            /// `text-match` media type
+            pub fn text_match(mut self) -> Self {
+                if let Ok(mut req) = self.request {
+                    req.get_mut().headers_mut().insert(
+                        ACCEPT,
+                        HeaderValue::from_static("application/vnd.github.v3.text-match+json"),
+                    );
+                    self.request = Ok(req);
+                }
+                self
+            }
+        }
+        )*
+    );
+}
+
+search!(
+    SearchCode
+    SearchCommits
+    SearchIssues
+    SearchLabels
+    SearchRepositories
+    SearchTopics
+    SearchUsers
+);
+
+exec!(SearchCode);
+exec!(SearchCommits);
+exec!(SearchIssues);
+exec!(SearchLabels);
+exec!(SearchRepositories);
+exec!(SearchTopics);
+exec!(SearchUsers);
@@ -3,3 +3,17 @@ pub mod get;
 pub mod patch;
 pub mod post;
 pub mod put;
+mod query;
+
+pub use self::query::{SearchQuery, SearchRange};
+
+/// The body returned by every search endpoint. `T` is the type of each item,
+/// or `serde_json::Value` if you don't want to deserialize them yourself.
+#[derive(Debug, Clone, Deserialize)]
+pub struct SearchResults<T> {
+    pub total_count: u64,
+    /// Set when GitHub timed out running the query and `items` only holds
+    /// the matches found before then
+    pub incomplete_results: bool,
+    pub items: Vec<T>,
+}
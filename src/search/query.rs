@@ -0,0 +1,189 @@
+//! Build up the `q` parameter of a search without worrying about quoting
+use std::fmt;
+
+/// A search query made up of keywords and qualifiers. Values containing
+/// whitespace are quoted for you and the finished query is percent encoded
+/// when it's added to the url, so it can be handed straight to `query` on any
+/// of the search endpoints.
+///
+/// ```text
+/// let q = SearchQuery::new()
+///     .repo("github-rs", "github-rs")
+///     .is("pr")
+///     .is("open")
+///     .label("needs review")
+///     .created(SearchRange::after("2024-01-01"));
+/// // repo:github-rs/github-rs is:pr is:open label:"needs review" created:>2024-01-01
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SearchQuery {
+    terms: Vec<String>,
+}
+
+impl SearchQuery {
+    /// Start an empty query
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add keywords to search for. A phrase with spaces is quoted so it's
+    /// matched as a whole.
+    pub fn keyword(self, keyword: &str) -> Self {
+        let keyword = quote(keyword);
+        self.push(keyword)
+    }
+
+    /// Add any `key:value` qualifier, for the ones without a dedicated
+    /// function
+    pub fn qualifier(self, key: &str, value: &str) -> Self {
+        let term = format!("{}:{}", key, quote(value));
+        self.push(term)
+    }
+
+    /// Exclude results matching a `key:value` qualifier, e.g. `-label:bug`
+    pub fn exclude(self, key: &str, value: &str) -> Self {
+        let term = format!("-{}:{}", key, quote(value));
+        self.push(term)
+    }
+
+    /// Limit results to a repository with `repo:owner/repo`
+    pub fn repo(self, owner: &str, repo: &str) -> Self {
+        self.qualifier("repo", &format!("{}/{}", owner, repo))
+    }
+
+    /// Limit results to an organization with `org:`
+    pub fn org(self, org: &str) -> Self {
+        self.qualifier("org", org)
+    }
+
+    /// Limit results to a user's account with `user:`
+    pub fn user(self, user: &str) -> Self {
+        self.qualifier("user", user)
+    }
+
+    /// Filter with `is:`, e.g. `pr`, `issue`, `open`, `closed` or `merged`
+    pub fn is(self, value: &str) -> Self {
+        self.qualifier("is", value)
+    }
+
+    /// Filter on a label with `label:`
+    pub fn label(self, label: &str) -> Self {
+        self.qualifier("label", label)
+    }
+
+    /// Filter on who opened an issue or pull request with `author:`
+    pub fn author(self, author: &str) -> Self {
+        self.qualifier("author", author)
+    }
+
+    /// Filter on the language of a repository or file with `language:`
+    pub fn language(self, language: &str) -> Self {
+        self.qualifier("language", language)
+    }
+
+    /// Filter on when something was created with `created:`
+    pub fn created(self, range: SearchRange) -> Self {
+        self.qualifier("created", &range.to_string())
+    }
+
+    /// Filter on when something was last updated with `updated:`
+    pub fn updated(self, range: SearchRange) -> Self {
+        self.qualifier("updated", &range.to_string())
+    }
+
+    /// Only match keywords against titles with `in:title`
+    pub fn in_title(self) -> Self {
+        self.qualifier("in", "title")
+    }
+
+    /// Only match keywords against bodies with `in:body`
+    pub fn in_body(self) -> Self {
+        self.qualifier("in", "body")
+    }
+
+    /// Only match keywords against comments with `in:comments`
+    pub fn in_comments(self) -> Self {
+        self.qualifier("in", "comments")
+    }
+
+    fn push(mut self, term: String) -> Self {
+        if !term.is_empty() {
+            self.terms.push(term);
+        }
+        self
+    }
+}
+
+impl fmt::Display for SearchQuery {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.terms.join(" "))
+    }
+}
+
+/// A range of dates (or numbers) for qualifiers like `created:` and
+/// `updated:`. Dates are given in ISO 8601 format, e.g. `2024-01-01`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum SearchRange {
+    /// `>value`
+    After(String),
+    /// `>=value`
+    OnOrAfter(String),
+    /// `<value`
+    Before(String),
+    /// `<=value`
+    OnOrBefore(String),
+    /// `start..end`, both ends included
+    Between(String, String),
+    /// Exactly `value`
+    On(String),
+}
+
+impl SearchRange {
+    pub fn after(value: &str) -> Self {
+        SearchRange::After(value.to_string())
+    }
+
+    pub fn on_or_after(value: &str) -> Self {
+        SearchRange::OnOrAfter(value.to_string())
+    }
+
+    pub fn before(value: &str) -> Self {
+        SearchRange::Before(value.to_string())
+    }
+
+    pub fn on_or_before(value: &str) -> Self {
+        SearchRange::OnOrBefore(value.to_string())
+    }
+
+    pub fn between(start: &str, end: &str) -> Self {
+        SearchRange::Between(start.to_string(), end.to_string())
+    }
+
+    pub fn on(value: &str) -> Self {
+        SearchRange::On(value.to_string())
+    }
+}
+
+impl fmt::Display for SearchRange {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            SearchRange::After(ref v) => write!(f, ">{}", v),
+            SearchRange::OnOrAfter(ref v) => write!(f, ">={}", v),
+            SearchRange::Before(ref v) => write!(f, "<{}", v),
+            SearchRange::OnOrBefore(ref v) => write!(f, "<={}", v),
+            SearchRange::Between(ref start, ref end) => write!(f, "{}..{}", start, end),
+            SearchRange::On(ref v) => write!(f, "{}", v),
+        }
+    }
+}
+
+/// Wrap a value in double quotes if it contains whitespace. GitHub has no way
+/// to escape a double quote inside a quoted value so any are dropped.
+fn quote(value: &str) -> String {
+    let value = value.replace('"', "");
+    if value.contains(char::is_whitespace) {
+        format!("\"{}\"", value)
+    } else {
+        value
+    }
+}
@@ -1 +1,75 @@
+//! Access the Teams portion of the Github API
+imports!();
+use crate::client::DeleteQueryBuilder;
 
+new_type!(
+    Teams
+    TeamsId
+    TeamsIdDiscussions
+    TeamsIdDiscussionsNumber
+    TeamsIdDiscussionsNumberComments
+    TeamsIdDiscussionsNumberCommentsNumber
+    TeamsIdMemberships
+    TeamsIdMembershipsUsername
+    TeamsIdRepos
+    TeamsIdReposOwner
+    TeamsIdReposOwnerRepo
+);
+
+from!(
+    @DeleteQueryBuilder
+        -> Teams = "teams"
+    @Teams
+        => TeamsId
+    @TeamsId
+        -> TeamsIdDiscussions = "discussions"
+        -> TeamsIdMemberships = "memberships"
+        -> TeamsIdRepos = "repos"
+    @TeamsIdDiscussions
+        => TeamsIdDiscussionsNumber
+    @TeamsIdDiscussionsNumber
+        -> TeamsIdDiscussionsNumberComments = "comments"
+    @TeamsIdDiscussionsNumberComments
+        => TeamsIdDiscussionsNumberCommentsNumber
+    @TeamsIdMemberships
+        => TeamsIdMembershipsUsername
+    @TeamsIdRepos
+        => TeamsIdReposOwner
+    @TeamsIdReposOwner
+        => TeamsIdReposOwnerRepo
+);
+
+impl_macro!(
+    @Teams
+        |
+        |=> id -> TeamsId = team_id
+    @TeamsId
+        |=> discussions -> TeamsIdDiscussions
+        |=> memberships -> TeamsIdMemberships
+        |=> repos -> TeamsIdRepos
+        |
+    @TeamsIdDiscussions
+        |
+        |=> number -> TeamsIdDiscussionsNumber = discussion_number
+    @TeamsIdDiscussionsNumber
+        |=> comments -> TeamsIdDiscussionsNumberComments
+        |
+    @TeamsIdDiscussionsNumberComments
+        |
+        |=> number -> TeamsIdDiscussionsNumberCommentsNumber = comment_number
+    @TeamsIdMemberships
+        |
+        |=> username -> TeamsIdMembershipsUsername = username_str
+    @TeamsIdRepos
+        |
+        |=> owner -> TeamsIdReposOwner = owner_str
+    @TeamsIdReposOwner
+        |
+        |=> repo -> TeamsIdReposOwnerRepo = repo_str
+);
+
+exec!(TeamsId);
+exec!(TeamsIdDiscussionsNumber);
+exec!(TeamsIdDiscussionsNumberCommentsNumber);
+exec!(TeamsIdMembershipsUsername);
+exec!(TeamsIdReposOwnerRepo);
@@ -1 +1,110 @@
+//! Access the Teams portion of the Github API
+//!
+//! These are the legacy routes that address a team by its id. The same
+//! endpoints keyed by organization and team slug are under `orgs`.
+imports!();
+use crate::client::GetQueryBuilder;
 
+new_type!(
+    Teams
+    TeamsId
+    TeamsIdDiscussions
+    TeamsIdDiscussionsNumber
+    TeamsIdDiscussionsNumberComments
+    TeamsIdInvitations
+    TeamsIdMembers
+    TeamsIdMemberships
+    TeamsIdMembershipsUsername
+    TeamsIdRepos
+    TeamsIdReposOwner
+    TeamsIdReposOwnerRepo
+    TeamsIdTeams
+);
+
+from!(
+    @GetQueryBuilder
+        -> Teams = "teams"
+    @Teams
+        => TeamsId
+    @TeamsId
+        -> TeamsIdDiscussions = "discussions"
+        -> TeamsIdInvitations = "invitations"
+        -> TeamsIdMembers = "members"
+        -> TeamsIdMemberships = "memberships"
+        -> TeamsIdRepos = "repos"
+        -> TeamsIdTeams = "teams"
+    @TeamsIdDiscussions
+        => TeamsIdDiscussionsNumber
+    @TeamsIdDiscussionsNumber
+        -> TeamsIdDiscussionsNumberComments = "comments"
+    @TeamsIdMemberships
+        => TeamsIdMembershipsUsername
+    @TeamsIdRepos
+        => TeamsIdReposOwner
+    @TeamsIdReposOwner
+        => TeamsIdReposOwnerRepo
+);
+
+impl_macro!(
+    @Teams
+        |
+        |=> id -> TeamsId = team_id
+    @TeamsId
+        |=> discussions -> TeamsIdDiscussions
+        |=> invitations -> TeamsIdInvitations
+        |=> members -> TeamsIdMembers
+        |=> memberships -> TeamsIdMemberships
+        |=> repos -> TeamsIdRepos
+        |=> teams -> TeamsIdTeams
+        |
+    @TeamsIdDiscussions
+        |
+        |=> number -> TeamsIdDiscussionsNumber = discussion_number
+    @TeamsIdDiscussionsNumber
+        |=> comments -> TeamsIdDiscussionsNumberComments
+        |
+    @TeamsIdMemberships
+        |
+        |=> username -> TeamsIdMembershipsUsername = username_str
+    @TeamsIdRepos
+        |
+        |=> owner -> TeamsIdReposOwner = owner_str
+    @TeamsIdReposOwner
+        |
+        |=> repo -> TeamsIdReposOwnerRepo = repo_str
+);
+
+params!(
+    @TeamsIdDiscussions
+        |?> direction = "direction"
+        |?> per_page = "per_page"
+        |?> page = "page"
+    @TeamsIdDiscussionsNumberComments
+        |?> direction = "direction"
+        |?> per_page = "per_page"
+        |?> page = "page"
+    @TeamsIdInvitations
+        |?> per_page = "per_page"
+        |?> page = "page"
+    @TeamsIdMembers
+        |?> role = "role"
+        |?> per_page = "per_page"
+        |?> page = "page"
+    @TeamsIdRepos
+        |?> per_page = "per_page"
+        |?> page = "page"
+    @TeamsIdTeams
+        |?> per_page = "per_page"
+        |?> page = "page"
+);
+
+exec!(TeamsId);
+exec!(TeamsIdDiscussions);
+exec!(TeamsIdDiscussionsNumber);
+exec!(TeamsIdDiscussionsNumberComments);
+exec!(TeamsIdInvitations);
+exec!(TeamsIdMembers);
+exec!(TeamsIdMembershipsUsername);
+exec!(TeamsIdRepos);
+exec!(TeamsIdReposOwnerRepo);
+exec!(TeamsIdTeams);
@@ -1 +1,49 @@
+//! Access the Teams portion of the Github API
+imports!();
+use crate::client::PatchQueryBuilder;
 
+new_type!(
+    Teams
+    TeamsId
+    TeamsIdDiscussions
+    TeamsIdDiscussionsNumber
+    TeamsIdDiscussionsNumberComments
+    TeamsIdDiscussionsNumberCommentsNumber
+);
+
+from!(
+    @PatchQueryBuilder
+        -> Teams = "teams"
+    @Teams
+        => TeamsId
+    @TeamsId
+        -> TeamsIdDiscussions = "discussions"
+    @TeamsIdDiscussions
+        => TeamsIdDiscussionsNumber
+    @TeamsIdDiscussionsNumber
+        -> TeamsIdDiscussionsNumberComments = "comments"
+    @TeamsIdDiscussionsNumberComments
+        => TeamsIdDiscussionsNumberCommentsNumber
+);
+
+impl_macro!(
+    @Teams
+        |
+        |=> id -> TeamsId = team_id
+    @TeamsId
+        |=> discussions -> TeamsIdDiscussions
+        |
+    @TeamsIdDiscussions
+        |
+        |=> number -> TeamsIdDiscussionsNumber = discussion_number
+    @TeamsIdDiscussionsNumber
+        |=> comments -> TeamsIdDiscussionsNumberComments
+        |
+    @TeamsIdDiscussionsNumberComments
+        |
+        |=> number -> TeamsIdDiscussionsNumberCommentsNumber = comment_number
+);
+
+exec!(TeamsId);
+exec!(TeamsIdDiscussionsNumber);
+exec!(TeamsIdDiscussionsNumberCommentsNumber);
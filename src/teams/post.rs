@@ -1 +1,42 @@
+//! Access the Teams portion of the Github API
+imports!();
+use crate::client::PostQueryBuilder;
 
+new_type!(
+    Teams
+    TeamsId
+    TeamsIdDiscussions
+    TeamsIdDiscussionsNumber
+    TeamsIdDiscussionsNumberComments
+);
+
+from!(
+    @PostQueryBuilder
+        -> Teams = "teams"
+    @Teams
+        => TeamsId
+    @TeamsId
+        -> TeamsIdDiscussions = "discussions"
+    @TeamsIdDiscussions
+        => TeamsIdDiscussionsNumber
+    @TeamsIdDiscussionsNumber
+        -> TeamsIdDiscussionsNumberComments = "comments"
+);
+
+impl_macro!(
+    @Teams
+        |
+        |=> id -> TeamsId = team_id
+    @TeamsId
+        |=> discussions -> TeamsIdDiscussions
+        |
+    @TeamsIdDiscussions
+        |
+        |=> number -> TeamsIdDiscussionsNumber = discussion_number
+    @TeamsIdDiscussionsNumber
+        |=> comments -> TeamsIdDiscussionsNumberComments
+        |
+);
+
+exec!(TeamsIdDiscussions);
+exec!(TeamsIdDiscussionsNumberComments);
@@ -1 +1,51 @@
+//! Access the Teams portion of the Github API
+imports!();
+use crate::client::PutQueryBuilder;
 
+new_type!(
+    Teams
+    TeamsId
+    TeamsIdMemberships
+    TeamsIdMembershipsUsername
+    TeamsIdRepos
+    TeamsIdReposOwner
+    TeamsIdReposOwnerRepo
+);
+
+from!(
+    @PutQueryBuilder
+        -> Teams = "teams"
+    @Teams
+        => TeamsId
+    @TeamsId
+        -> TeamsIdMemberships = "memberships"
+        -> TeamsIdRepos = "repos"
+    @TeamsIdMemberships
+        => TeamsIdMembershipsUsername
+    @TeamsIdRepos
+        => TeamsIdReposOwner
+    @TeamsIdReposOwner
+        => TeamsIdReposOwnerRepo
+);
+
+impl_macro!(
+    @Teams
+        |
+        |=> id -> TeamsId = team_id
+    @TeamsId
+        |=> memberships -> TeamsIdMemberships
+        |=> repos -> TeamsIdRepos
+        |
+    @TeamsIdMemberships
+        |
+        |=> username -> TeamsIdMembershipsUsername = username_str
+    @TeamsIdRepos
+        |
+        |=> owner -> TeamsIdReposOwner = owner_str
+    @TeamsIdReposOwner
+        |
+        |=> repo -> TeamsIdReposOwnerRepo = repo_str
+);
+
+exec!(TeamsIdMembershipsUsername);
+exec!(TeamsIdReposOwnerRepo);
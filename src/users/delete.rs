@@ -5,6 +5,8 @@ use crate::client::DeleteQueryBuilder;
 new_type!(
     User
     Emails
+    RepositoryInvitations
+    RepositoryInvitationsId
 );
 
 from!(
@@ -12,12 +14,20 @@ from!(
        -> User = "user"
     @User
        -> Emails = "emails"
+       -> RepositoryInvitations = "repository_invitations"
+    @RepositoryInvitations
+       => RepositoryInvitationsId
 );
 
 impl_macro!(
     @User
         |=> emails -> Emails
+        |=> repository_invitations -> RepositoryInvitations
         |
+    @RepositoryInvitations
+        |
+        |=> id -> RepositoryInvitationsId = invitation_id
 );
 
 exec!(Emails);
+exec!(RepositoryInvitationsId);
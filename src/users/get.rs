@@ -26,6 +26,7 @@ new_type!(
     ReceivedEvents
     Issues
     Public
+    RepositoryInvitations
     Starred
     StarredRepo
     StarredOwner
@@ -60,6 +61,7 @@ from!(
         -> Repos = "repos"
         -> Subscriptions = "subscriptions"
         -> Starred = "starred"
+        -> RepositoryInvitations = "repository_invitations"
     @Users
         => UsersUsername
     @UserUsername
@@ -98,6 +100,7 @@ impl_macro!(
         |=> starred -> Starred
         |=> keys -> Keys
         |=> orgs -> Orgs
+        |=> repository_invitations -> RepositoryInvitations
         |
     @Users
         |
@@ -135,6 +138,12 @@ impl_macro!(
         |=> username -> Following = username_str
 );
 
+params!(
+    @RepositoryInvitations
+        |?> per_page = "per_page"
+        |?> page = "page"
+);
+
 exec!(Emails);
 exec!(Events);
 exec!(EventsOrgsName);
@@ -149,6 +158,7 @@ exec!(Orgs);
 exec!(Public);
 exec!(ReceivedEvents);
 exec!(Repos);
+exec!(RepositoryInvitations);
 exec!(Starred);
 exec!(StarredRepo);
 exec!(Subscriptions);
@@ -5,6 +5,8 @@ use crate::client::PatchQueryBuilder;
 new_type!(
     User
     Email
+    RepositoryInvitations
+    RepositoryInvitationsId
     Visibility
 );
 
@@ -13,17 +15,25 @@ from!(
         -> User = "user"
     @User
         -> Email = "email"
+        -> RepositoryInvitations = "repository_invitations"
     @Email
         -> Visibility = "visibility"
+    @RepositoryInvitations
+        => RepositoryInvitationsId
 );
 
 impl_macro!(
     @User
         |=> emails -> Email
+        |=> repository_invitations -> RepositoryInvitations
         |
     @Email
         |=> visibility -> Visibility
         |
+    @RepositoryInvitations
+        |
+        |=> id -> RepositoryInvitationsId = invitation_id
 );
 
+exec!(RepositoryInvitationsId);
 exec!(Visibility);
@@ -5,6 +5,7 @@ use crate::client::PostQueryBuilder;
 new_type!(
     User
     Emails
+    Repos
 );
 
 from!(
@@ -12,12 +13,15 @@ from!(
         -> User = "user"
     @User
         -> Emails = "emails"
+        -> Repos = "repos"
 );
 
 impl_macro!(
     @User
         |=> emails -> Emails
+        |=> repos -> Repos
         |
 );
 
 exec!(Emails);
+exec!(Repos);
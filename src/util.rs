@@ -2,6 +2,10 @@ use hyper::http::uri::InvalidUriParts;
 use hyper::http::HttpTryFrom;
 use hyper::Uri;
 
+/// Characters that have to be escaped when used in a path segment. `?` is
+/// left alone so custom endpoints can still carry a query string.
+const PATH_ESCAPE: &[u8] = b"\"#<>`{}|\\^";
+
 /// Add an extra subdirectory to the end of the url. This utilizes
 /// Hyper's more generic Uri type. We've set it up to act as a Url.
 pub fn url_join(url: &Uri, path: &str) -> Result<Uri, InvalidUriParts> {
@@ -15,7 +19,53 @@ pub fn url_join(url: &Uri, path: &str) -> Result<Uri, InvalidUriParts> {
     if !curr_path.ends_with('/') {
         curr_path.push('/');
     }
-    curr_path.push_str(path);
+    for byte in path.bytes() {
+        // Anything that isn't allowed in a path, e.g. the space in a label
+        // name like "help wanted", needs to be percent encoded
+        if byte <= 0x20 || byte >= 0x7F || PATH_ESCAPE.contains(&byte) {
+            curr_path.push_str(&format!("%{:02X}", byte));
+        } else {
+            curr_path.push(byte as char);
+        }
+    }
     parts.path_and_query = HttpTryFrom::try_from(curr_path.as_str()).ok();
     Uri::from_parts(parts)
 }
+
+/// Add a query parameter to the end of the url. The value is percent encoded
+/// so things like label lists or search qualifiers can be passed as is.
+pub fn url_query(url: &Uri, key: &str, value: &str) -> Result<Uri, InvalidUriParts> {
+    let mut parts = url.clone().into_parts();
+    let p = parts.path_and_query.take();
+    let mut path_and_query = match p {
+        Some(ref p) => String::from(p.as_str()),
+        None => String::from("/"),
+    };
+    path_and_query.push(if path_and_query.contains('?') {
+        '&'
+    } else {
+        '?'
+    });
+    path_and_query.push_str(key);
+    path_and_query.push('=');
+    path_and_query.push_str(&encode_path_segment(value));
+    parts.path_and_query = HttpTryFrom::try_from(path_and_query.as_str()).ok();
+    Uri::from_parts(parts)
+}
+
+/// Percent encode everything but the unreserved characters so the value can
+/// be used as a single path segment or query value. Unlike `url_join` this
+/// escapes `/`, `?` and `%`, which is what names like the label `kind/bug`
+/// need. It's also handy when building the string for `custom_endpoint`.
+pub fn encode_path_segment(value: &str) -> String {
+    let mut encoded = String::with_capacity(value.len());
+    for byte in value.bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => {
+                encoded.push(byte as char)
+            }
+            _ => encoded.push_str(&format!("%{:02X}", byte)),
+        }
+    }
+    encoded
+}
@@ -0,0 +1,475 @@
+//! Typed payloads for GitHub webhook deliveries
+//!
+//! Once a delivery has been authenticated with
+//! `headers::verify_signature` its body can be turned into a `WebhookEvent`
+//! using the name GitHub sends in the `X-GitHub-Event` header:
+//!
+//! ```text
+//! if verify_signature(&headers, secret, &body) {
+//!     match WebhookEvent::from_headers(&headers, &body)? {
+//!         WebhookEvent::Push(push) => println!("pushed to {}", push.git_ref),
+//!         WebhookEvent::Ping(_) => println!("pong"),
+//!         _ => {}
+//!     }
+//! }
+//! ```
+//!
+//! Only the commonly used fields of each payload are typed. Events this
+//! library doesn't know about are handed back as `WebhookEvent::Unknown`
+//! with the raw JSON so nothing is lost.
+use crate::errors::*;
+use crate::headers::github_event;
+pub use crate::models::User;
+use hyper::HeaderMap;
+use serde_json::{self, Value};
+
+/// A webhook delivery parsed according to its `X-GitHub-Event` header
+#[derive(Debug, Clone)]
+pub enum WebhookEvent {
+    CheckRun(CheckRunEvent),
+    CheckSuite(CheckSuiteEvent),
+    Create(CreateEvent),
+    Delete(DeleteEvent),
+    IssueComment(IssueCommentEvent),
+    Issues(IssuesEvent),
+    Ping(PingEvent),
+    PullRequest(PullRequestEvent),
+    PullRequestReview(PullRequestReviewEvent),
+    Push(PushEvent),
+    Release(ReleaseEvent),
+    WorkflowRun(WorkflowRunEvent),
+    /// Any event without a typed payload in this library
+    Unknown(Value),
+}
+
+impl WebhookEvent {
+    /// Parse the body of a delivery given the event name GitHub sent in the
+    /// `X-GitHub-Event` header. Unrecognized event names are returned as
+    /// `Unknown`, while a recognized event with a body that doesn't match its
+    /// payload is an error.
+    pub fn parse(event: &str, body: &[u8]) -> Result<Self> {
+        Ok(match event {
+            "check_run" => WebhookEvent::CheckRun(serde_json::from_slice(body)?),
+            "check_suite" => WebhookEvent::CheckSuite(serde_json::from_slice(body)?),
+            "create" => WebhookEvent::Create(serde_json::from_slice(body)?),
+            "delete" => WebhookEvent::Delete(serde_json::from_slice(body)?),
+            "issue_comment" => WebhookEvent::IssueComment(serde_json::from_slice(body)?),
+            "issues" => WebhookEvent::Issues(serde_json::from_slice(body)?),
+            "ping" => WebhookEvent::Ping(serde_json::from_slice(body)?),
+            "pull_request" => WebhookEvent::PullRequest(serde_json::from_slice(body)?),
+            "pull_request_review" => WebhookEvent::PullRequestReview(serde_json::from_slice(body)?),
+            "push" => WebhookEvent::Push(serde_json::from_slice(body)?),
+            "release" => WebhookEvent::Release(serde_json::from_slice(body)?),
+            "workflow_run" => WebhookEvent::WorkflowRun(serde_json::from_slice(body)?),
+            _ => WebhookEvent::Unknown(serde_json::from_slice(body)?),
+        })
+    }
+
+    /// Parse the body of a delivery using the `X-GitHub-Event` header from
+    /// the request it came with
+    pub fn from_headers(head: &HeaderMap, body: &[u8]) -> Result<Self> {
+        let event = github_event(head).ok_or("Missing X-GitHub-Event header")?;
+        Self::parse(event, body)
+    }
+}
+
+/// The repository an event happened in
+#[derive(Debug, Clone, Deserialize)]
+pub struct Repository {
+    pub id: u64,
+    pub node_id: Option<String>,
+    pub name: String,
+    pub full_name: String,
+    pub owner: User,
+    #[serde(default)]
+    pub private: bool,
+    pub html_url: String,
+    pub description: Option<String>,
+    #[serde(default)]
+    pub fork: bool,
+    pub default_branch: Option<String>,
+}
+
+/// The organization an event happened in, if any
+#[derive(Debug, Clone, Deserialize)]
+pub struct Organization {
+    pub login: String,
+    pub id: u64,
+    pub node_id: Option<String>,
+}
+
+/// The GitHub App installation a delivery was sent for
+#[derive(Debug, Clone, Deserialize)]
+pub struct Installation {
+    pub id: u64,
+    pub node_id: Option<String>,
+}
+
+/// A label attached to an issue or pull request
+#[derive(Debug, Clone, Deserialize)]
+pub struct Label {
+    pub id: u64,
+    pub name: String,
+    pub color: String,
+    pub description: Option<String>,
+    #[serde(default)]
+    pub default: bool,
+}
+
+/// An issue. Pull requests show up as issues with `pull_request` set.
+#[derive(Debug, Clone, Deserialize)]
+pub struct Issue {
+    pub id: u64,
+    pub number: u64,
+    pub title: String,
+    pub body: Option<String>,
+    pub state: String,
+    pub user: User,
+    #[serde(default)]
+    pub labels: Vec<Label>,
+    #[serde(default)]
+    pub assignees: Vec<User>,
+    #[serde(default)]
+    pub locked: bool,
+    pub html_url: String,
+    pub created_at: String,
+    pub updated_at: String,
+    pub closed_at: Option<String>,
+    pub pull_request: Option<Value>,
+}
+
+/// A comment on an issue or pull request conversation
+#[derive(Debug, Clone, Deserialize)]
+pub struct Comment {
+    pub id: u64,
+    pub body: String,
+    pub user: User,
+    pub html_url: String,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+/// One side of a pull request, either the head or the base
+#[derive(Debug, Clone, Deserialize)]
+pub struct PullRequestRef {
+    pub label: String,
+    #[serde(rename = "ref")]
+    pub git_ref: String,
+    pub sha: String,
+    pub repo: Option<Repository>,
+}
+
+/// A pull request
+#[derive(Debug, Clone, Deserialize)]
+pub struct PullRequest {
+    pub id: u64,
+    pub number: u64,
+    pub title: String,
+    pub body: Option<String>,
+    pub state: String,
+    pub user: User,
+    #[serde(default)]
+    pub draft: bool,
+    pub merged: Option<bool>,
+    pub merge_commit_sha: Option<String>,
+    pub head: PullRequestRef,
+    pub base: PullRequestRef,
+    #[serde(default)]
+    pub labels: Vec<Label>,
+    pub html_url: String,
+    pub created_at: String,
+    pub updated_at: String,
+    pub closed_at: Option<String>,
+    pub merged_at: Option<String>,
+}
+
+/// A review left on a pull request
+#[derive(Debug, Clone, Deserialize)]
+pub struct Review {
+    pub id: u64,
+    pub user: User,
+    pub body: Option<String>,
+    pub state: String,
+    pub commit_id: Option<String>,
+    pub html_url: String,
+    pub submitted_at: Option<String>,
+}
+
+/// The author or committer of a pushed commit
+#[derive(Debug, Clone, Deserialize)]
+pub struct CommitAuthor {
+    pub name: String,
+    pub email: Option<String>,
+    pub username: Option<String>,
+}
+
+/// A commit included in a push
+#[derive(Debug, Clone, Deserialize)]
+pub struct Commit {
+    pub id: String,
+    pub tree_id: String,
+    pub message: String,
+    pub timestamp: String,
+    pub url: String,
+    pub author: CommitAuthor,
+    pub committer: CommitAuthor,
+    #[serde(default)]
+    pub distinct: bool,
+    #[serde(default)]
+    pub added: Vec<String>,
+    #[serde(default)]
+    pub removed: Vec<String>,
+    #[serde(default)]
+    pub modified: Vec<String>,
+}
+
+/// A release
+#[derive(Debug, Clone, Deserialize)]
+pub struct Release {
+    pub id: u64,
+    pub tag_name: String,
+    pub target_commitish: String,
+    pub name: Option<String>,
+    pub body: Option<String>,
+    #[serde(default)]
+    pub draft: bool,
+    #[serde(default)]
+    pub prerelease: bool,
+    pub author: User,
+    pub html_url: String,
+    pub created_at: String,
+    pub published_at: Option<String>,
+}
+
+/// The check suite a check run belongs to, as embedded in a check run
+#[derive(Debug, Clone, Deserialize)]
+pub struct CheckSuiteRef {
+    pub id: u64,
+    pub head_branch: Option<String>,
+    pub head_sha: String,
+}
+
+/// A check run
+#[derive(Debug, Clone, Deserialize)]
+pub struct CheckRun {
+    pub id: u64,
+    pub name: String,
+    pub head_sha: String,
+    pub external_id: Option<String>,
+    pub status: String,
+    pub conclusion: Option<String>,
+    pub html_url: Option<String>,
+    pub details_url: Option<String>,
+    pub started_at: Option<String>,
+    pub completed_at: Option<String>,
+    pub check_suite: Option<CheckSuiteRef>,
+}
+
+/// A check suite
+#[derive(Debug, Clone, Deserialize)]
+pub struct CheckSuite {
+    pub id: u64,
+    pub head_branch: Option<String>,
+    pub head_sha: String,
+    pub status: Option<String>,
+    pub conclusion: Option<String>,
+    pub before: Option<String>,
+    pub after: Option<String>,
+}
+
+/// A GitHub Actions workflow run
+#[derive(Debug, Clone, Deserialize)]
+pub struct WorkflowRun {
+    pub id: u64,
+    pub name: Option<String>,
+    pub workflow_id: u64,
+    pub run_number: u64,
+    pub run_attempt: Option<u64>,
+    pub event: String,
+    pub head_branch: Option<String>,
+    pub head_sha: String,
+    pub status: Option<String>,
+    pub conclusion: Option<String>,
+    pub html_url: String,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+/// The webhook described by a `ping` event
+#[derive(Debug, Clone, Deserialize)]
+pub struct Hook {
+    pub id: u64,
+    #[serde(rename = "type")]
+    pub hook_type: String,
+    pub name: String,
+    pub active: bool,
+    #[serde(default)]
+    pub events: Vec<String>,
+    pub config: Value,
+}
+
+/// Payload of the `check_run` event
+#[derive(Debug, Clone, Deserialize)]
+pub struct CheckRunEvent {
+    pub action: String,
+    pub check_run: CheckRun,
+    pub requested_action: Option<Value>,
+    pub repository: Repository,
+    pub organization: Option<Organization>,
+    pub installation: Option<Installation>,
+    pub sender: User,
+}
+
+/// Payload of the `check_suite` event
+#[derive(Debug, Clone, Deserialize)]
+pub struct CheckSuiteEvent {
+    pub action: String,
+    pub check_suite: CheckSuite,
+    pub repository: Repository,
+    pub organization: Option<Organization>,
+    pub installation: Option<Installation>,
+    pub sender: User,
+}
+
+/// Payload of the `create` event, sent when a branch or tag is created
+#[derive(Debug, Clone, Deserialize)]
+pub struct CreateEvent {
+    #[serde(rename = "ref")]
+    pub git_ref: String,
+    pub ref_type: String,
+    pub master_branch: String,
+    pub description: Option<String>,
+    pub repository: Repository,
+    pub organization: Option<Organization>,
+    pub installation: Option<Installation>,
+    pub sender: User,
+}
+
+/// Payload of the `delete` event, sent when a branch or tag is deleted
+#[derive(Debug, Clone, Deserialize)]
+pub struct DeleteEvent {
+    #[serde(rename = "ref")]
+    pub git_ref: String,
+    pub ref_type: String,
+    pub repository: Repository,
+    pub organization: Option<Organization>,
+    pub installation: Option<Installation>,
+    pub sender: User,
+}
+
+/// Payload of the `issue_comment` event
+#[derive(Debug, Clone, Deserialize)]
+pub struct IssueCommentEvent {
+    pub action: String,
+    pub issue: Issue,
+    pub comment: Comment,
+    pub changes: Option<Value>,
+    pub repository: Repository,
+    pub organization: Option<Organization>,
+    pub installation: Option<Installation>,
+    pub sender: User,
+}
+
+/// Payload of the `issues` event
+#[derive(Debug, Clone, Deserialize)]
+pub struct IssuesEvent {
+    pub action: String,
+    pub issue: Issue,
+    pub changes: Option<Value>,
+    pub label: Option<Label>,
+    pub assignee: Option<User>,
+    pub repository: Repository,
+    pub organization: Option<Organization>,
+    pub installation: Option<Installation>,
+    pub sender: User,
+}
+
+/// Payload of the `ping` event, sent when a webhook is created
+#[derive(Debug, Clone, Deserialize)]
+pub struct PingEvent {
+    pub zen: String,
+    pub hook_id: u64,
+    pub hook: Hook,
+    pub repository: Option<Repository>,
+    pub organization: Option<Organization>,
+    pub sender: Option<User>,
+}
+
+/// Payload of the `pull_request` event
+#[derive(Debug, Clone, Deserialize)]
+pub struct PullRequestEvent {
+    pub action: String,
+    pub number: u64,
+    pub pull_request: PullRequest,
+    pub changes: Option<Value>,
+    pub label: Option<Label>,
+    pub assignee: Option<User>,
+    pub requested_reviewer: Option<User>,
+    pub before: Option<String>,
+    pub after: Option<String>,
+    pub repository: Repository,
+    pub organization: Option<Organization>,
+    pub installation: Option<Installation>,
+    pub sender: User,
+}
+
+/// Payload of the `pull_request_review` event
+#[derive(Debug, Clone, Deserialize)]
+pub struct PullRequestReviewEvent {
+    pub action: String,
+    pub review: Review,
+    pub pull_request: PullRequest,
+    pub repository: Repository,
+    pub organization: Option<Organization>,
+    pub installation: Option<Installation>,
+    pub sender: User,
+}
+
+/// Payload of the `push` event
+#[derive(Debug, Clone, Deserialize)]
+pub struct PushEvent {
+    #[serde(rename = "ref")]
+    pub git_ref: String,
+    pub before: String,
+    pub after: String,
+    #[serde(default)]
+    pub created: bool,
+    #[serde(default)]
+    pub deleted: bool,
+    #[serde(default)]
+    pub forced: bool,
+    pub base_ref: Option<String>,
+    pub compare: String,
+    #[serde(default)]
+    pub commits: Vec<Commit>,
+    pub head_commit: Option<Commit>,
+    pub pusher: CommitAuthor,
+    pub repository: Repository,
+    pub organization: Option<Organization>,
+    pub installation: Option<Installation>,
+    pub sender: User,
+}
+
+/// Payload of the `release` event
+#[derive(Debug, Clone, Deserialize)]
+pub struct ReleaseEvent {
+    pub action: String,
+    pub release: Release,
+    pub changes: Option<Value>,
+    pub repository: Repository,
+    pub organization: Option<Organization>,
+    pub installation: Option<Installation>,
+    pub sender: User,
+}
+
+/// Payload of the `workflow_run` event
+#[derive(Debug, Clone, Deserialize)]
+pub struct WorkflowRunEvent {
+    pub action: String,
+    pub workflow_run: WorkflowRun,
+    pub workflow: Option<Value>,
+    pub repository: Repository,
+    pub organization: Option<Organization>,
+    pub installation: Option<Installation>,
+    pub sender: User,
+}
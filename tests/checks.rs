@@ -0,0 +1,114 @@
+use github_rs as gh;
+#[macro_use]
+extern crate serde_json;
+
+use gh::repos::{
+    Annotation, CheckRunOutput, CheckSuitePreferences, CreateCheckRun, Image, UpdateCheckRun,
+};
+
+fn annotations(count: u64) -> Vec<Annotation> {
+    (1..=count)
+        .map(|line| Annotation::new("src/lib.rs", line, line, "warning", "unused variable"))
+        .collect()
+}
+
+#[test]
+fn serialize_check_run() {
+    let run = CreateCheckRun::new("lint", "ce587453ced02b1526dfb4cb910479d431683101")
+        .status("completed")
+        .conclusion("neutral")
+        .output(
+            CheckRunOutput::new("Lint", "1 warning").annotations(vec![Annotation::new(
+                "README.md",
+                2,
+                2,
+                "warning",
+                "Check your spelling",
+            )
+            .columns(5, 10)
+            .title("Spell checker")]),
+        );
+    assert_eq!(
+        serde_json::to_value(&run).unwrap(),
+        json!({
+            "name": "lint",
+            "head_sha": "ce587453ced02b1526dfb4cb910479d431683101",
+            "status": "completed",
+            "conclusion": "neutral",
+            "output": {
+                "title": "Lint",
+                "summary": "1 warning",
+                "annotations": [{
+                    "path": "README.md",
+                    "start_line": 2,
+                    "end_line": 2,
+                    "start_column": 5,
+                    "end_column": 10,
+                    "annotation_level": "warning",
+                    "message": "Check your spelling",
+                    "title": "Spell checker"
+                }]
+            }
+        })
+    );
+}
+
+#[test]
+fn batch_annotations() {
+    let image = Image {
+        alt: "Coverage".to_string(),
+        image_url: "https://example.com/coverage.png".to_string(),
+        caption: None,
+    };
+    let run = CreateCheckRun::new("lint", "ce587453ced02b1526dfb4cb910479d431683101")
+        .details_url("https://ci.example.com/42")
+        .conclusion("failure")
+        .output(
+            CheckRunOutput::new("Lint", "120 warnings")
+                .annotations(annotations(120))
+                .image(image),
+        );
+    let (create, updates) = run.batched();
+
+    assert_eq!(create.name, "lint");
+    assert_eq!(
+        create.fields.details_url.as_ref().unwrap(),
+        "https://ci.example.com/42"
+    );
+    assert!(create.fields.conclusion.is_none());
+    let output = create.fields.output.unwrap();
+    assert_eq!(output.annotations.len(), 50);
+    assert_eq!(output.images.len(), 1);
+
+    assert_eq!(updates.len(), 2);
+    let first = updates[0].fields.output.as_ref().unwrap();
+    assert_eq!(first.annotations.len(), 50);
+    assert_eq!(first.annotations[0].start_line, 51);
+    assert_eq!(first.summary, "120 warnings");
+    assert!(first.images.is_empty());
+    assert!(updates[0].fields.conclusion.is_none());
+    assert_eq!(
+        updates[1].fields.output.as_ref().unwrap().annotations.len(),
+        20
+    );
+    assert_eq!(updates[1].fields.conclusion.as_ref().unwrap(), "failure");
+}
+
+#[test]
+fn small_updates_are_not_split() {
+    let update = UpdateCheckRun::new()
+        .name("lint")
+        .conclusion("success")
+        .output(CheckRunOutput::new("Lint", "50 warnings").annotations(annotations(50)));
+    let batches = update.clone().batched();
+    assert_eq!(batches, vec![update]);
+}
+
+#[test]
+fn serialize_preferences() {
+    let preferences = CheckSuitePreferences::new().auto_trigger(4, false);
+    assert_eq!(
+        serde_json::to_value(&preferences).unwrap(),
+        json!({ "auto_trigger_checks": [{ "app_id": 4, "setting": false }] })
+    );
+}
@@ -0,0 +1,65 @@
+use github_rs as gh;
+#[macro_use]
+extern crate serde_json;
+
+use gh::repos::{CommitBuilder, FileMode};
+
+fn builder() -> CommitBuilder {
+    CommitBuilder::new("octocat", "Hello-World", "main", "Sync config")
+        .add("config/app.toml", b"debug = false\n")
+        .delete("config/old.toml")
+        .add_with_mode("bin/deploy.sh", b"#!/bin/sh\n", FileMode::Executable)
+        .add_with_mode("current", b"releases/v1.2.0", FileMode::Symlink)
+        .committer("sync-bot", "sync-bot@example.com")
+}
+
+#[test]
+fn blob_bodies() {
+    let blobs = builder().blobs();
+    assert_eq!(blobs.len(), 3);
+    assert_eq!(
+        serde_json::to_value(&blobs[0]).unwrap(),
+        json!({ "content": "ZGVidWcgPSBmYWxzZQo=", "encoding": "base64" })
+    );
+}
+
+#[test]
+fn tree_body() {
+    let shas = vec!["aaa".to_string(), "bbb".to_string(), "ccc".to_string()];
+    let tree = builder().tree("base", &shas).unwrap();
+    assert_eq!(
+        serde_json::to_value(&tree).unwrap(),
+        json!({
+            "base_tree": "base",
+            "tree": [
+                { "path": "config/app.toml", "mode": "100644", "type": "blob", "sha": "aaa" },
+                { "path": "config/old.toml", "mode": "100644", "type": "blob", "sha": null },
+                { "path": "bin/deploy.sh", "mode": "100755", "type": "blob", "sha": "bbb" },
+                { "path": "current", "mode": "120000", "type": "blob", "sha": "ccc" }
+            ]
+        })
+    );
+
+    // A missing blob sha must not turn an add into a delete
+    assert!(builder().tree("base", &shas[..2]).is_err());
+    let mut extra = shas.clone();
+    extra.push("ddd".to_string());
+    assert!(builder().tree("base", &extra).is_err());
+}
+
+#[test]
+fn commit_body() {
+    let commit = builder()
+        .author("Monalisa Octocat", "octocat@github.com")
+        .commit("tree", "head");
+    assert_eq!(
+        serde_json::to_value(&commit).unwrap(),
+        json!({
+            "message": "Sync config",
+            "tree": "tree",
+            "parents": ["head"],
+            "author": { "name": "Monalisa Octocat", "email": "octocat@github.com" },
+            "committer": { "name": "sync-bot", "email": "sync-bot@example.com" }
+        })
+    );
+}
@@ -0,0 +1,88 @@
+use github_rs as gh;
+#[macro_use]
+extern crate serde_json;
+
+use gh::repos::{decode_content, encode_content, DeleteFile, FileContent, UpdateFile};
+
+#[test]
+fn content_round_trip() {
+    let encoded = encode_content(b"Hello, World!\n");
+    assert_eq!(encoded, "SGVsbG8sIFdvcmxkIQo=");
+    assert_eq!(decode_content(&encoded).unwrap(), b"Hello, World!\n");
+
+    // GitHub wraps the encoded content over several lines
+    assert_eq!(
+        decode_content("SGVsbG8s\nIFdvcmxk\nIQo=\n").unwrap(),
+        b"Hello, World!\n"
+    );
+    assert!(decode_content("not base64!").is_err());
+}
+
+#[test]
+fn decode_file_content() {
+    let file: FileContent = serde_json::from_value(json!({
+        "type": "file",
+        "encoding": "base64",
+        "size": 14,
+        "name": "README.md",
+        "path": "README.md",
+        "sha": "3d21ec53a331a6f037a91c368710b99387d012c1",
+        "content": "SGVsbG8sIFdvcmxk\nIQo=\n",
+        "html_url": "https://github.com/octocat/Hello-World/blob/main/README.md",
+        "download_url": "https://raw.githubusercontent.com/octocat/Hello-World/main/README.md"
+    }))
+    .unwrap();
+    assert_eq!(file.kind, "file");
+    assert_eq!(file.decode().unwrap(), b"Hello, World!\n");
+}
+
+#[test]
+fn decode_large_file_content() {
+    // Files over 1MB come back without their contents
+    let file: FileContent = serde_json::from_value(json!({
+        "type": "file",
+        "encoding": "none",
+        "size": 2097152,
+        "name": "data.bin",
+        "path": "assets/data.bin",
+        "sha": "3d21ec53a331a6f037a91c368710b99387d012c1",
+        "content": "",
+        "download_url": "https://raw.githubusercontent.com/octocat/Hello-World/main/assets/data.bin"
+    }))
+    .unwrap();
+    assert!(file.decode().is_err());
+
+    let file = FileContent {
+        encoding: None,
+        content: None,
+        ..file
+    };
+    assert!(file.decode().is_err());
+}
+
+#[test]
+fn update_and_delete_bodies() {
+    let update = UpdateFile::new("Update README", b"Hello, World!\n")
+        .sha("3d21ec53a331a6f037a91c368710b99387d012c1")
+        .branch("main")
+        .committer("Monalisa Octocat", "octocat@github.com");
+    assert_eq!(
+        serde_json::to_value(&update).unwrap(),
+        json!({
+            "message": "Update README",
+            "content": "SGVsbG8sIFdvcmxkIQo=",
+            "sha": "3d21ec53a331a6f037a91c368710b99387d012c1",
+            "branch": "main",
+            "committer": { "name": "Monalisa Octocat", "email": "octocat@github.com" }
+        })
+    );
+
+    let delete = DeleteFile::new("Remove README", "3d21ec53a331a6f037a91c368710b99387d012c1");
+    assert_eq!(
+        serde_json::to_value(&delete).unwrap(),
+        json!({
+            "message": "Remove README",
+            "sha": "3d21ec53a331a6f037a91c368710b99387d012c1"
+        })
+    );
+}
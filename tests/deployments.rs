@@ -0,0 +1,116 @@
+use github_rs as gh;
+#[macro_use]
+extern crate serde_json;
+
+use gh::repos::{
+    CreateDeployment, CreateDeploymentStatus, Deployment, DeploymentBranchPolicy, Environment,
+    UpdateEnvironment,
+};
+
+#[test]
+fn serialize_deployment_bodies() {
+    let deployment = CreateDeployment::new("v1.2.0")
+        .environment("production")
+        .required_contexts(&[])
+        .payload(json!({ "build": 42 }));
+    assert_eq!(
+        serde_json::to_value(&deployment).unwrap(),
+        json!({
+            "ref": "v1.2.0",
+            "required_contexts": [],
+            "payload": { "build": 42 },
+            "environment": "production"
+        })
+    );
+
+    let status = CreateDeploymentStatus::new("success")
+        .log_url("https://ci.example.com/42")
+        .auto_inactive(true);
+    assert_eq!(
+        serde_json::to_value(&status).unwrap(),
+        json!({
+            "state": "success",
+            "log_url": "https://ci.example.com/42",
+            "auto_inactive": true
+        })
+    );
+}
+
+#[test]
+fn serialize_environment() {
+    let environment = UpdateEnvironment::new()
+        .wait_timer(30)
+        .user_reviewer(1)
+        .team_reviewer(2)
+        .deployment_branch_policy(DeploymentBranchPolicy {
+            protected_branches: true,
+            custom_branch_policies: false,
+        });
+    assert_eq!(
+        serde_json::to_value(&environment).unwrap(),
+        json!({
+            "wait_timer": 30,
+            "reviewers": [{ "type": "User", "id": 1 }, { "type": "Team", "id": 2 }],
+            "deployment_branch_policy": { "protected_branches": true, "custom_branch_policies": false }
+        })
+    );
+}
+
+#[test]
+fn reset_deployment_branch_policy() {
+    // Leaving the policy out keeps it, null lets every branch deploy
+    assert_eq!(
+        serde_json::to_value(UpdateEnvironment::new().wait_timer(5)).unwrap(),
+        json!({ "wait_timer": 5 })
+    );
+    assert_eq!(
+        serde_json::to_value(UpdateEnvironment::new().allow_all_branches()).unwrap(),
+        json!({ "deployment_branch_policy": null })
+    );
+}
+
+#[test]
+fn deserialize_deployment_and_environment() {
+    let deployment: Deployment = serde_json::from_value(json!({
+        "id": 1,
+        "node_id": "MDEwOkRlcGxveW1lbnQx",
+        "sha": "a84d88e7554fc1fa21bcbc4efae3c782a70d2b9d",
+        "ref": "topic-branch",
+        "task": "deploy",
+        "payload": {},
+        "original_environment": "staging",
+        "environment": "production",
+        "description": "Deploy request from hubot",
+        "creator": { "login": "octocat", "id": 1 },
+        "created_at": "2012-07-20T01:19:13Z",
+        "updated_at": "2012-07-20T01:19:13Z",
+        "statuses_url": "https://api.github.com/repos/octocat/example/deployments/1/statuses",
+        "transient_environment": false,
+        "production_environment": true
+    }))
+    .unwrap();
+    assert_eq!(deployment.git_ref, "topic-branch");
+    assert_eq!(deployment.creator.unwrap().login, "octocat");
+
+    let environment: Environment = serde_json::from_value(json!({
+        "id": 161088068,
+        "node_id": "MDExOkVudmlyb25tZW50MTYxMDg4MDY4",
+        "name": "staging",
+        "html_url": "https://github.com/github/hello-world/deployments/activity_log?environments_filter=staging",
+        "created_at": "2020-11-23T22:00:40Z",
+        "updated_at": "2020-11-23T22:00:40Z",
+        "protection_rules": [
+            { "id": 3736, "node_id": "MDQ6R2F0ZTM3MzY=", "type": "wait_timer", "wait_timer": 30 },
+            { "id": 3755, "node_id": "MDQ6R2F0ZTM3NTU=", "type": "branch_policy" }
+        ],
+        "deployment_branch_policy": { "protected_branches": false, "custom_branch_policies": true }
+    }))
+    .unwrap();
+    assert_eq!(environment.protection_rules[0].wait_timer, Some(30));
+    assert!(
+        environment
+            .deployment_branch_policy
+            .unwrap()
+            .custom_branch_policies
+    );
+}
@@ -0,0 +1,83 @@
+use gh::client::Executor;
+use gh::StatusCode;
+use github_rs as gh;
+use serde_json::Value;
+
+mod testutil;
+
+use testutil::*;
+
+#[test]
+fn get_ref_commit_and_tree() {
+    let g = setup_github_connection();
+    let (headers, status, json) = g
+        .get()
+        .repos()
+        .owner("github-rs")
+        .repo("github-rs")
+        .git()
+        .git_ref()
+        .reference("heads/master")
+        .execute::<Value>()
+        .expect(testutil::FAILED_GITHUB_CONNECTION);
+    println!("{:#?}", headers);
+    println!("{}", status);
+    assert_eq!(status, StatusCode::OK);
+    let json = json.unwrap();
+    let sha = json["object"]["sha"].as_str().unwrap();
+
+    let (headers, status, json) = g
+        .get()
+        .repos()
+        .owner("github-rs")
+        .repo("github-rs")
+        .git()
+        .commits()
+        .sha(sha)
+        .execute::<Value>()
+        .expect(testutil::FAILED_GITHUB_CONNECTION);
+    println!("{:#?}", headers);
+    println!("{}", status);
+    assert_eq!(status, StatusCode::OK);
+    let json = json.unwrap();
+    let tree = json["tree"]["sha"].as_str().unwrap();
+
+    let (headers, status, json) = g
+        .get()
+        .repos()
+        .owner("github-rs")
+        .repo("github-rs")
+        .git()
+        .trees()
+        .sha(tree)
+        .recursive("1")
+        .execute::<Value>()
+        .expect(testutil::FAILED_GITHUB_CONNECTION);
+    println!("{:#?}", headers);
+    println!("{}", status);
+    assert_eq!(status, StatusCode::OK);
+    if let Some(json) = json {
+        println!("{}", json);
+    }
+}
+
+#[test]
+fn get_matching_refs() {
+    let g = setup_github_connection();
+    let (headers, status, json) = g
+        .get()
+        .repos()
+        .owner("github-rs")
+        .repo("github-rs")
+        .git()
+        .matching_refs()
+        .reference("tags")
+        .execute::<Value>()
+        .expect(testutil::FAILED_GITHUB_CONNECTION);
+    println!("{:#?}", headers);
+    println!("{}", status);
+    assert_eq!(status, StatusCode::OK);
+    if let Some(json) = json {
+        println!("{}", json);
+    }
+}
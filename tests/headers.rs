@@ -0,0 +1,75 @@
+use github_rs as gh;
+
+use gh::headers::{
+    github_delivery, github_event, github_hook_id, verify_signature, verify_signature_256,
+    verify_signature_sha1,
+};
+use gh::HeaderMap;
+
+// Test vector taken from GitHub's "Validating webhook deliveries" docs
+const SECRET: &[u8] = b"It's a Secret to Everybody";
+const BODY: &[u8] = b"Hello, World!";
+const SHA256: &str = "sha256=757107ea0eb2509fc211221cce984b8a37570b6d7586c22c46f4379c8b043e17";
+const SHA1: &str = "sha1=01dc10d0c83e72ed246219cdd91669667fe2ca59";
+
+fn delivery(headers: &[(&'static str, &str)]) -> HeaderMap {
+    let mut head = HeaderMap::new();
+    for (name, value) in headers {
+        head.insert(*name, value.parse().unwrap());
+    }
+    head
+}
+
+#[test]
+fn signature_256() {
+    let head = delivery(&[("X-Hub-Signature-256", SHA256)]);
+    assert!(verify_signature_256(&head, SECRET, BODY));
+    assert!(verify_signature(&head, SECRET, BODY));
+    assert!(!verify_signature_256(&head, b"wrong secret", BODY));
+    assert!(!verify_signature_256(&head, SECRET, b"Hello, World?"));
+}
+
+#[test]
+fn signature_sha1() {
+    let head = delivery(&[("X-Hub-Signature", SHA1)]);
+    assert!(verify_signature_sha1(&head, SECRET, BODY));
+    assert!(verify_signature(&head, SECRET, BODY));
+    assert!(!verify_signature_sha1(&head, b"wrong secret", BODY));
+}
+
+#[test]
+fn signature_256_preferred() {
+    // A valid legacy signature shouldn't rescue a bad SHA256 one
+    let head = delivery(&[
+        ("X-Hub-Signature-256", "sha256=00"),
+        ("X-Hub-Signature", SHA1),
+    ]);
+    assert!(!verify_signature(&head, SECRET, BODY));
+}
+
+#[test]
+fn signature_malformed() {
+    assert!(!verify_signature(&HeaderMap::new(), SECRET, BODY));
+    let head = delivery(&[("X-Hub-Signature-256", &SHA256[7..])]);
+    assert!(!verify_signature_256(&head, SECRET, BODY));
+    let head = delivery(&[("X-Hub-Signature-256", "sha256=zz")]);
+    assert!(!verify_signature_256(&head, SECRET, BODY));
+    let head = delivery(&[("X-Hub-Signature-256", "sha256=abc")]);
+    assert!(!verify_signature_256(&head, SECRET, BODY));
+}
+
+#[test]
+fn delivery_headers() {
+    let head = delivery(&[
+        ("X-GitHub-Event", "push"),
+        ("X-GitHub-Delivery", "72d3162e-cc78-11e3-81ab-4c9367dc0958"),
+        ("X-GitHub-Hook-ID", "292430182"),
+    ]);
+    assert_eq!(github_event(&head), Some("push"));
+    assert_eq!(
+        github_delivery(&head),
+        Some("72d3162e-cc78-11e3-81ab-4c9367dc0958")
+    );
+    assert_eq!(github_hook_id(&head), Some(292430182));
+    assert_eq!(github_event(&HeaderMap::new()), None);
+}
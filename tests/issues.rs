@@ -0,0 +1,151 @@
+use gh::client::Executor;
+use gh::StatusCode;
+use github_rs as gh;
+use serde_json::Value;
+
+mod testutil;
+
+use testutil::*;
+
+#[test]
+fn get_issues() {
+    let g = setup_github_connection();
+    let (headers, status, json) = g
+        .get()
+        .issues()
+        .filter("all")
+        .state("closed")
+        .per_page("5")
+        .execute::<Value>()
+        .expect(testutil::FAILED_GITHUB_CONNECTION);
+    println!("{:#?}", headers);
+    println!("{}", status);
+    assert_eq!(status, StatusCode::OK);
+    if let Some(json) = json {
+        println!("{}", json);
+    }
+}
+
+#[test]
+fn get_issue_events_and_timeline() {
+    let g = setup_github_connection();
+    let (headers, status, json) = g
+        .get()
+        .repos()
+        .owner("github-rs")
+        .repo("github-rs")
+        .issues()
+        .number("1")
+        .events()
+        .execute::<Value>()
+        .expect(testutil::FAILED_GITHUB_CONNECTION);
+    println!("{:#?}", headers);
+    println!("{}", status);
+    assert_eq!(status, StatusCode::OK);
+    if let Some(json) = json {
+        println!("{}", json);
+    }
+
+    let (headers, status, json) = g
+        .get()
+        .repos()
+        .owner("github-rs")
+        .repo("github-rs")
+        .issues()
+        .number("1")
+        .timeline()
+        .execute::<Value>()
+        .expect(testutil::FAILED_GITHUB_CONNECTION);
+    println!("{:#?}", headers);
+    println!("{}", status);
+    assert_eq!(status, StatusCode::OK);
+    if let Some(json) = json {
+        println!("{}", json);
+    }
+}
+
+#[test]
+fn get_issue_comments_since() {
+    let g = setup_github_connection();
+    let (headers, status, json) = g
+        .get()
+        .repos()
+        .owner("github-rs")
+        .repo("github-rs")
+        .issues()
+        .number("1")
+        .comments()
+        .since("2017-01-01T00:00:00Z")
+        .execute::<Value>()
+        .expect(testutil::FAILED_GITHUB_CONNECTION);
+    println!("{:#?}", headers);
+    println!("{}", status);
+    assert_eq!(status, StatusCode::OK);
+    if let Some(json) = json {
+        println!("{}", json);
+    }
+}
+
+#[test]
+fn get_repo_labels() {
+    let g = setup_github_connection();
+    let (headers, status, json) = g
+        .get()
+        .repos()
+        .owner("github-rs")
+        .repo("github-rs")
+        .labels()
+        .execute::<Value>()
+        .expect(testutil::FAILED_GITHUB_CONNECTION);
+    println!("{:#?}", headers);
+    println!("{}", status);
+    assert_eq!(status, StatusCode::OK);
+    if let Some(json) = json {
+        println!("{}", json);
+    }
+
+    // Label names with spaces have to be encoded in the url
+    let (headers, status, _) = g
+        .get()
+        .repos()
+        .owner("github-rs")
+        .repo("github-rs")
+        .labels()
+        .name("help wanted")
+        .execute::<Value>()
+        .expect(testutil::FAILED_GITHUB_CONNECTION);
+    println!("{:#?}", headers);
+    println!("{}", status);
+    assert_eq!(status, StatusCode::OK);
+}
+
+#[test]
+fn encode_label_names() {
+    assert_eq!(gh::encode_path_segment("help wanted"), "help%20wanted");
+    assert_eq!(gh::encode_path_segment("kind/bug"), "kind%2Fbug");
+    assert_eq!(gh::encode_path_segment("what?"), "what%3F");
+    assert_eq!(gh::encode_path_segment("50%"), "50%25");
+    assert_eq!(gh::encode_path_segment("v1.2-rc_1~"), "v1.2-rc_1~");
+}
+
+#[test]
+fn get_milestones() {
+    let g = setup_github_connection();
+    let (headers, status, json) = g
+        .get()
+        .repos()
+        .owner("github-rs")
+        .repo("github-rs")
+        .milestones()
+        .state("all")
+        .sort("due_on")
+        .direction("desc")
+        .execute::<Value>()
+        .expect(testutil::FAILED_GITHUB_CONNECTION);
+    println!("{:#?}", headers);
+    println!("{}", status);
+    assert_eq!(status, StatusCode::OK);
+    if let Some(json) = json {
+        println!("{}", json);
+    }
+}
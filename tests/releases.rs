@@ -0,0 +1,81 @@
+use gh::client::Executor;
+use gh::StatusCode;
+use github_rs as gh;
+use serde_json::Value;
+
+mod testutil;
+
+use testutil::*;
+
+#[test]
+fn get_releases() {
+    let g = setup_github_connection();
+    let (headers, status, json) = g
+        .get()
+        .repos()
+        .owner("rust-lang")
+        .repo("rust")
+        .releases()
+        .per_page("5")
+        .execute::<Value>()
+        .expect(testutil::FAILED_GITHUB_CONNECTION);
+    println!("{:#?}", headers);
+    println!("{}", status);
+    assert_eq!(status, StatusCode::OK);
+    if let Some(json) = json {
+        println!("{}", json);
+    }
+
+    let (headers, status, json) = g
+        .get()
+        .repos()
+        .owner("rust-lang")
+        .repo("rust")
+        .releases()
+        .tags()
+        .tag("1.0.0")
+        .execute::<Value>()
+        .expect(testutil::FAILED_GITHUB_CONNECTION);
+    println!("{:#?}", headers);
+    println!("{}", status);
+    assert_eq!(status, StatusCode::OK);
+    let json = json.unwrap();
+    let id = json["id"].to_string();
+
+    let (headers, status, json) = g
+        .get()
+        .repos()
+        .owner("rust-lang")
+        .repo("rust")
+        .releases()
+        .id(&id)
+        .assets()
+        .execute::<Value>()
+        .expect(testutil::FAILED_GITHUB_CONNECTION);
+    println!("{:#?}", headers);
+    println!("{}", status);
+    assert_eq!(status, StatusCode::OK);
+    if let Some(json) = json {
+        println!("{}", json);
+    }
+}
+
+#[test]
+fn get_latest_release() {
+    let g = setup_github_connection();
+    let (headers, status, json) = g
+        .get()
+        .repos()
+        .owner("rust-lang")
+        .repo("rust")
+        .releases()
+        .latest()
+        .execute::<Value>()
+        .expect(testutil::FAILED_GITHUB_CONNECTION);
+    println!("{:#?}", headers);
+    println!("{}", status);
+    assert_eq!(status, StatusCode::OK);
+    if let Some(json) = json {
+        println!("{}", json);
+    }
+}
@@ -0,0 +1,216 @@
+use github_rs as gh;
+#[macro_use]
+extern crate serde_json;
+
+use gh::repos::{BranchRule, PatternParameters, PullRequestParameters, Rule, Ruleset};
+
+#[test]
+fn serialize_ruleset() {
+    let ruleset = Ruleset::new("main", "active")
+        .target("branch")
+        .include_refs(&["~DEFAULT_BRANCH"])
+        .exclude_refs(&["refs/heads/dev/*"])
+        .rule(Rule::Deletion)
+        .rule(Rule::PullRequest(PullRequestParameters {
+            required_approving_review_count: 1,
+            ..Default::default()
+        }))
+        .rule(Rule::BranchNamePattern(PatternParameters {
+            operator: "starts_with".to_string(),
+            pattern: "feature/".to_string(),
+            ..Default::default()
+        }));
+    assert_eq!(
+        serde_json::to_value(&ruleset).unwrap(),
+        json!({
+            "name": "main",
+            "target": "branch",
+            "enforcement": "active",
+            "conditions": {
+                "ref_name": { "include": ["~DEFAULT_BRANCH"], "exclude": ["refs/heads/dev/*"] }
+            },
+            "rules": [
+                { "type": "deletion" },
+                {
+                    "type": "pull_request",
+                    "parameters": {
+                        "dismiss_stale_reviews_on_push": false,
+                        "require_code_owner_review": false,
+                        "require_last_push_approval": false,
+                        "required_approving_review_count": 1,
+                        "required_review_thread_resolution": false
+                    }
+                },
+                {
+                    "type": "branch_name_pattern",
+                    "parameters": { "negate": false, "operator": "starts_with", "pattern": "feature/" }
+                }
+            ]
+        })
+    );
+}
+
+#[test]
+fn deserialize_ruleset() {
+    let ruleset: Ruleset = serde_json::from_value(json!({
+        "id": 42,
+        "name": "main",
+        "target": "branch",
+        "source_type": "Repository",
+        "source": "octocat/Hello-World",
+        "enforcement": "evaluate",
+        "bypass_actors": [{ "actor_id": 234, "actor_type": "Team", "bypass_mode": "always" }],
+        "conditions": {
+            "ref_name": { "include": ["refs/heads/main"], "exclude": [] },
+            "repository_id": { "repository_ids": [1296269] }
+        },
+        "rules": [
+            { "type": "non_fast_forward" },
+            { "type": "update", "parameters": { "update_allows_fetch_and_merge": true } },
+            {
+                "type": "required_status_checks",
+                "parameters": {
+                    "required_status_checks": [{ "context": "ci", "integration_id": 15368 }],
+                    "strict_required_status_checks_policy": true,
+                    "do_not_enforce_on_create": true
+                }
+            },
+            { "type": "file_path_restriction", "parameters": { "restricted_file_paths": [] } },
+            {
+                "type": "pull_request",
+                "parameters": {
+                    "allowed_merge_methods": ["squash"],
+                    "dismiss_stale_reviews_on_push": true,
+                    "require_code_owner_review": true,
+                    "require_last_push_approval": false,
+                    "required_approving_review_count": 2,
+                    "required_review_thread_resolution": true
+                }
+            }
+        ]
+    }))
+    .unwrap();
+    assert_eq!(ruleset.id, Some(42));
+    assert_eq!(
+        ruleset.bypass_actors.as_ref().unwrap()[0].actor_type,
+        "Team"
+    );
+    let rules = ruleset.rules.as_ref().unwrap();
+    assert_eq!(rules[0], Rule::NonFastForward);
+    match rules[2] {
+        Rule::RequiredStatusChecks(ref checks) => {
+            assert_eq!(checks.required_status_checks[0].context, "ci");
+            assert!(checks.strict_required_status_checks_policy);
+        }
+        ref other => panic!("Expected required status checks, got {:?}", other),
+    }
+    match rules[3] {
+        Rule::Unknown { ref kind, .. } => assert_eq!(kind, "file_path_restriction"),
+        ref other => panic!("Expected an unknown rule, got {:?}", other),
+    }
+    // Unknown rules are written back as they were read
+    assert_eq!(
+        serde_json::to_value(&rules[3]).unwrap(),
+        json!({ "type": "file_path_restriction", "parameters": { "restricted_file_paths": [] } })
+    );
+    // Parameters of known rules that aren't modeled are kept too
+    match rules[4] {
+        Rule::PullRequest(ref pull_request) => {
+            assert_eq!(pull_request.required_approving_review_count, 2);
+            assert_eq!(
+                pull_request.extra["allowed_merge_methods"],
+                json!(["squash"])
+            );
+        }
+        ref other => panic!("Expected a pull request rule, got {:?}", other),
+    }
+    assert_eq!(
+        serde_json::to_value(&rules[4]).unwrap(),
+        json!({
+            "type": "pull_request",
+            "parameters": {
+                "allowed_merge_methods": ["squash"],
+                "dismiss_stale_reviews_on_push": true,
+                "require_code_owner_review": true,
+                "require_last_push_approval": false,
+                "required_approving_review_count": 2,
+                "required_review_thread_resolution": true
+            }
+        })
+    );
+    assert_eq!(
+        serde_json::to_value(&rules[2]).unwrap()["parameters"]["do_not_enforce_on_create"],
+        json!(true)
+    );
+    // So are conditions
+    assert_eq!(
+        serde_json::to_value(&ruleset.conditions).unwrap(),
+        json!({
+            "ref_name": { "include": ["refs/heads/main"], "exclude": [] },
+            "repository_id": { "repository_ids": [1296269] }
+        })
+    );
+}
+
+#[test]
+fn clear_bypass_actors_conditions_and_rules() {
+    let body = |ruleset: &Ruleset| serde_json::to_value(ruleset).unwrap();
+
+    // Leaving them out keeps what's already on GitHub
+    let ruleset = Ruleset::new("main", "active");
+    assert_eq!(
+        body(&ruleset),
+        json!({ "name": "main", "enforcement": "active" })
+    );
+
+    let ruleset = ruleset
+        .clear_bypass_actors()
+        .clear_conditions()
+        .clear_rules();
+    assert_eq!(
+        body(&ruleset),
+        json!({
+            "name": "main",
+            "enforcement": "active",
+            "bypass_actors": [],
+            "conditions": {},
+            "rules": []
+        })
+    );
+
+    // Emptying the list of a ruleset that was read also clears them
+    let mut ruleset: Ruleset = serde_json::from_value(json!({
+        "name": "main",
+        "enforcement": "active",
+        "bypass_actors": [{ "actor_id": 234, "actor_type": "Team", "bypass_mode": "always" }]
+    }))
+    .unwrap();
+    ruleset.bypass_actors.as_mut().unwrap().clear();
+    assert_eq!(body(&ruleset)["bypass_actors"], json!([]));
+}
+
+#[test]
+fn deserialize_branch_rules() {
+    let rules: Vec<BranchRule> = serde_json::from_value(json!([
+        {
+            "type": "required_signatures",
+            "ruleset_source_type": "Organization",
+            "ruleset_source": "octo-org",
+            "ruleset_id": 7
+        },
+        {
+            "type": "commit_message_pattern",
+            "parameters": { "operator": "regex", "pattern": "^JIRA-\\d+" },
+            "ruleset_source_type": "Repository",
+            "ruleset_source": "octo-org/repo",
+            "ruleset_id": 8
+        }
+    ]))
+    .unwrap();
+    assert_eq!(rules[0].rule, Rule::RequiredSignatures);
+    assert_eq!(rules[0].ruleset_source, "octo-org");
+    match rules[1].rule {
+        Rule::CommitMessagePattern(ref pattern) => assert_eq!(pattern.operator, "regex"),
+        ref other => panic!("Expected a commit message pattern, got {:?}", other),
+    }
+}
@@ -0,0 +1,111 @@
+use gh::client::Executor;
+use gh::headers::rate_limit_resource;
+use gh::search::{SearchQuery, SearchRange, SearchResults};
+use gh::StatusCode;
+use github_rs as gh;
+use serde_json::Value;
+
+mod testutil;
+
+use testutil::*;
+
+#[test]
+fn search_repositories() {
+    let g = setup_github_connection();
+    let (headers, status, json) = g
+        .get()
+        .search()
+        .repositories()
+        .q("github-rs language:rust")
+        .sort("stars")
+        .order("desc")
+        .per_page("5")
+        .execute::<SearchResults<Value>>()
+        .expect(testutil::FAILED_GITHUB_CONNECTION);
+    println!("{:#?}", headers);
+    println!("{}", status);
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(rate_limit_resource(&headers), Some("search"));
+    if let Some(json) = json {
+        println!("{:?}", json);
+        assert!(json.items.len() <= 5);
+    }
+}
+
+#[test]
+fn search_issues_text_match() {
+    let g = setup_github_connection();
+    let (headers, status, json) = g
+        .get()
+        .search()
+        .issues()
+        .q("repo:github-rs/github-rs is:issue endpoint")
+        .text_match()
+        .execute::<Value>()
+        .expect(testutil::FAILED_GITHUB_CONNECTION);
+    println!("{:#?}", headers);
+    println!("{}", status);
+    assert_eq!(status, StatusCode::OK);
+    if let Some(json) = json {
+        println!("{}", json);
+    }
+}
+
+#[test]
+fn search_query_qualifiers() {
+    let q = SearchQuery::new()
+        .repo("github-rs", "github-rs")
+        .is("pr")
+        .is("open")
+        .label("needs review")
+        .created(SearchRange::after("2024-01-01"));
+    assert_eq!(
+        q.to_string(),
+        r#"repo:github-rs/github-rs is:pr is:open label:"needs review" created:>2024-01-01"#
+    );
+}
+
+#[test]
+fn search_query_keywords_and_ranges() {
+    let q = SearchQuery::new()
+        .keyword("memory leak")
+        .in_title()
+        .author("octocat")
+        .exclude("label", "wontfix")
+        .updated(SearchRange::between("2024-01-01", "2024-02-01"));
+    assert_eq!(
+        q.to_string(),
+        r#""memory leak" in:title author:octocat -label:wontfix updated:2024-01-01..2024-02-01"#
+    );
+    assert_eq!(
+        SearchRange::on_or_before("2020-05-05").to_string(),
+        "<=2020-05-05"
+    );
+    // Quotes can't be escaped in a search so they're dropped
+    assert_eq!(
+        SearchQuery::new().label(r#"say "hi""#).to_string(),
+        r#"label:"say hi""#
+    );
+}
+
+#[test]
+fn search_with_query() {
+    let g = setup_github_connection();
+    let q = SearchQuery::new()
+        .repo("github-rs", "github-rs")
+        .is("issue")
+        .created(SearchRange::before("2018-01-01"));
+    let (headers, status, json) = g
+        .get()
+        .search()
+        .issues()
+        .query(&q)
+        .execute::<SearchResults<Value>>()
+        .expect(testutil::FAILED_GITHUB_CONNECTION);
+    println!("{:#?}", headers);
+    println!("{}", status);
+    assert_eq!(status, StatusCode::OK);
+    if let Some(json) = json {
+        println!("{:?}", json);
+    }
+}
@@ -0,0 +1,61 @@
+use gh::client::Executor;
+use github_rs as gh;
+use serde_json::Value;
+
+mod testutil;
+
+use testutil::*;
+
+#[test]
+fn get_team_members() {
+    // Only members of the organization can see most teams, so this may well
+    // come back as a 404
+    let g = setup_github_connection();
+    let (headers, status, json) = g
+        .get()
+        .orgs()
+        .org("github-rs")
+        .teams()
+        .slug("maintainers")
+        .members()
+        .per_page("10")
+        .execute::<Value>()
+        .expect(testutil::FAILED_GITHUB_CONNECTION);
+    println!("{:#?}", headers);
+    println!("{}", status);
+    if let Some(json) = json {
+        println!("{}", json);
+    }
+}
+
+#[test]
+fn get_team_by_id() {
+    let g = setup_github_connection();
+    let (_, status, team) = g
+        .get()
+        .orgs()
+        .org("github-rs")
+        .teams()
+        .slug("maintainers")
+        .execute::<Value>()
+        .expect(testutil::FAILED_GITHUB_CONNECTION);
+    println!("{}", status);
+    let id = match team.as_ref().and_then(|t| t["id"].as_u64()) {
+        Some(id) => id.to_string(),
+        None => return,
+    };
+
+    // The legacy routes address the same team by its id
+    let (headers, status, json) = g
+        .get()
+        .teams()
+        .id(&id)
+        .repos()
+        .execute::<Value>()
+        .expect(testutil::FAILED_GITHUB_CONNECTION);
+    println!("{:#?}", headers);
+    println!("{}", status);
+    if let Some(json) = json {
+        println!("{}", json);
+    }
+}
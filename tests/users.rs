@@ -61,3 +61,20 @@ fn core_exposure() {
     let core_mut = core.try_borrow_mut().unwrap();
     let _ = core_mut.handle();
 }
+
+#[test]
+fn get_repository_invitations() {
+    let g = setup_github_connection();
+    let (headers, status, json) = g
+        .get()
+        .user()
+        .repository_invitations()
+        .per_page("10")
+        .execute::<Value>()
+        .expect(testutil::FAILED_GITHUB_CONNECTION);
+    println!("{:#?}", headers);
+    println!("{}", status);
+    if let Some(json) = json {
+        println!("{}", json);
+    }
+}
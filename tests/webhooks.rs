@@ -0,0 +1,157 @@
+use github_rs as gh;
+#[macro_use]
+extern crate serde_json;
+
+use gh::webhooks::WebhookEvent;
+use gh::HeaderMap;
+use serde_json::Value;
+
+fn user() -> Value {
+    json!({
+        "login": "octocat",
+        "id": 1,
+        "node_id": "MDQ6VXNlcjE=",
+        "html_url": "https://github.com/octocat",
+        "type": "User",
+        "site_admin": false
+    })
+}
+
+fn repository() -> Value {
+    json!({
+        "id": 1296269,
+        "node_id": "MDEwOlJlcG9zaXRvcnkxMjk2MjY5",
+        "name": "Hello-World",
+        "full_name": "octocat/Hello-World",
+        "owner": user(),
+        "private": false,
+        "html_url": "https://github.com/octocat/Hello-World",
+        "description": null,
+        "fork": false,
+        "default_branch": "main"
+    })
+}
+
+#[test]
+fn parse_ping() {
+    let body = json!({
+        "zen": "Keep it logically awesome.",
+        "hook_id": 42,
+        "hook": {
+            "id": 42,
+            "type": "Repository",
+            "name": "web",
+            "active": true,
+            "events": ["push", "pull_request"],
+            "config": { "content_type": "json", "url": "https://example.com/hook" }
+        },
+        "repository": repository(),
+        "sender": user()
+    });
+    match WebhookEvent::parse("ping", body.to_string().as_bytes()).unwrap() {
+        WebhookEvent::Ping(ping) => {
+            assert_eq!(ping.hook_id, 42);
+            assert_eq!(ping.hook.events, vec!["push", "pull_request"]);
+        }
+        other => panic!("Expected a ping event, got {:?}", other),
+    }
+}
+
+#[test]
+fn parse_push() {
+    let commit = json!({
+        "id": "0d1a26e67d8f5eaf1f6ba5c57fc3c7d91ac0fd1c",
+        "tree_id": "f9d2a07e9488b91af2641b26b9407fe22a451433",
+        "distinct": true,
+        "message": "Update README.md",
+        "timestamp": "2015-05-05T19:40:15-04:00",
+        "url": "https://github.com/octocat/Hello-World/commit/0d1a26e",
+        "author": { "name": "Monalisa Octocat", "email": "octocat@github.com", "username": "octocat" },
+        "committer": { "name": "Monalisa Octocat", "email": "octocat@github.com", "username": "octocat" },
+        "added": [],
+        "removed": [],
+        "modified": ["README.md"]
+    });
+    let body = json!({
+        "ref": "refs/heads/main",
+        "before": "9049f1265b7d61be4a8904a9a27120d2064dab3b",
+        "after": "0d1a26e67d8f5eaf1f6ba5c57fc3c7d91ac0fd1c",
+        "created": false,
+        "deleted": false,
+        "forced": false,
+        "base_ref": null,
+        "compare": "https://github.com/octocat/Hello-World/compare/9049f1265b7d...0d1a26e67d8f",
+        "commits": [commit.clone()],
+        "head_commit": commit,
+        "pusher": { "name": "octocat", "email": "octocat@github.com" },
+        "repository": repository(),
+        "sender": user()
+    });
+    let mut head = HeaderMap::new();
+    head.insert("X-GitHub-Event", "push".parse().unwrap());
+    match WebhookEvent::from_headers(&head, body.to_string().as_bytes()).unwrap() {
+        WebhookEvent::Push(push) => {
+            assert_eq!(push.git_ref, "refs/heads/main");
+            assert_eq!(push.commits[0].modified, vec!["README.md"]);
+            assert_eq!(push.repository.full_name, "octocat/Hello-World");
+        }
+        other => panic!("Expected a push event, got {:?}", other),
+    }
+}
+
+#[test]
+fn parse_issue_comment() {
+    let body = json!({
+        "action": "created",
+        "issue": {
+            "id": 1,
+            "number": 1347,
+            "title": "Found a bug",
+            "body": null,
+            "state": "open",
+            "user": user(),
+            "labels": [{ "id": 208045946, "name": "bug", "color": "f29513", "description": null, "default": true }],
+            "assignees": [],
+            "locked": false,
+            "html_url": "https://github.com/octocat/Hello-World/issues/1347",
+            "created_at": "2011-04-22T13:33:48Z",
+            "updated_at": "2011-04-22T13:33:48Z",
+            "closed_at": null
+        },
+        "comment": {
+            "id": 99262140,
+            "body": "You are totally right! I'll get this fixed right away.",
+            "user": user(),
+            "html_url": "https://github.com/octocat/Hello-World/issues/1347#issuecomment-99262140",
+            "created_at": "2015-05-05T23:40:28Z",
+            "updated_at": "2015-05-05T23:40:28Z"
+        },
+        "repository": repository(),
+        "sender": user()
+    });
+    match WebhookEvent::parse("issue_comment", body.to_string().as_bytes()).unwrap() {
+        WebhookEvent::IssueComment(event) => {
+            assert_eq!(event.action, "created");
+            assert_eq!(event.issue.number, 1347);
+            assert_eq!(event.issue.labels[0].name, "bug");
+            assert!(event.issue.pull_request.is_none());
+        }
+        other => panic!("Expected an issue_comment event, got {:?}", other),
+    }
+}
+
+#[test]
+fn parse_unknown() {
+    let body = json!({ "action": "starred", "sender": user() });
+    match WebhookEvent::parse("star", body.to_string().as_bytes()).unwrap() {
+        WebhookEvent::Unknown(value) => assert_eq!(value["action"], "starred"),
+        other => panic!("Expected an unknown event, got {:?}", other),
+    }
+}
+
+#[test]
+fn parse_errors() {
+    // Known events must match their payload
+    assert!(WebhookEvent::parse("push", b"{}").is_err());
+    assert!(WebhookEvent::from_headers(&HeaderMap::new(), b"{}").is_err());
+}